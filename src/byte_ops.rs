@@ -0,0 +1,627 @@
+use core::ops::Range;
+
+use crate::{
+	CollectionCursor, IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable,
+	NotEnoughItems, OutOfBoundsError, SeekFrom, Utf8CursorError, VarintError,
+};
+
+/// Generates methods which read a number from the bytes at the cursor, in each byte order.
+macro_rules! read_number {
+	($($ty:ident: $le:ident, $be:ident;)*) => {
+		$(
+			#[doc = concat!("Reads a little-endian `", stringify!($ty), "` from the bytes at the cursor, then moves the cursor forwards past them.")]
+			///
+			/// # Errors
+			/// If not enough bytes remain after the cursor, returns [`NotEnoughItems`]. In this
+			/// case, the cursor will not be moved.
+			pub fn $le(&mut self) -> Result<$ty, NotEnoughItems> {
+				let mut bytes = [0; size_of::<$ty>()];
+				self.read_exact_items(&mut bytes)?;
+				Ok($ty::from_le_bytes(bytes))
+			}
+
+			#[doc = concat!("Reads a big-endian `", stringify!($ty), "` from the bytes at the cursor, then moves the cursor forwards past them.")]
+			///
+			/// # Errors
+			/// If not enough bytes remain after the cursor, returns [`NotEnoughItems`]. In this
+			/// case, the cursor will not be moved.
+			pub fn $be(&mut self) -> Result<$ty, NotEnoughItems> {
+				let mut bytes = [0; size_of::<$ty>()];
+				self.read_exact_items(&mut bytes)?;
+				Ok($ty::from_be_bytes(bytes))
+			}
+		)*
+	};
+}
+
+/// Generates methods which write a number to the bytes at the cursor, in each byte order.
+macro_rules! write_number {
+	($($ty:ident: $le:ident, $be:ident;)*) => {
+		$(
+			#[doc = concat!("Writes `value` as a little-endian `", stringify!($ty), "` at the cursor, then moves the cursor forwards past the written bytes.")]
+			///
+			/// Existing bytes from the cursor onwards are overwritten. Once the end of the
+			/// collection is reached, the remaining bytes are appended to the collection.
+			///
+			/// # Panics
+			/// Panics if an insert operation panics. The circumstances for a panic are defined by
+			/// the inner collection, but will usually occur if the collection has a fixed capacity
+			/// which would be exceeded.
+			pub fn $le(&mut self, value: $ty) {
+				self.write_appending(&value.to_le_bytes());
+			}
+
+			#[doc = concat!("Writes `value` as a big-endian `", stringify!($ty), "` at the cursor, then moves the cursor forwards past the written bytes.")]
+			///
+			/// Existing bytes from the cursor onwards are overwritten. Once the end of the
+			/// collection is reached, the remaining bytes are appended to the collection.
+			///
+			/// # Panics
+			/// Panics if an insert operation panics. The circumstances for a panic are defined by
+			/// the inner collection, but will usually occur if the collection has a fixed capacity
+			/// which would be exceeded.
+			pub fn $be(&mut self, value: $ty) {
+				self.write_appending(&value.to_be_bytes());
+			}
+		)*
+	};
+}
+
+/// Generates methods which overwrite the bytes at the cursor with a number, in each byte order,
+/// without resizing the collection.
+macro_rules! overwrite_number {
+	($($ty:ident: $le:ident, $be:ident;)*) => {
+		$(
+			#[doc = concat!("Overwrites the bytes at the cursor with `value` as a little-endian `", stringify!($ty), "`, then moves the cursor forwards past them.")]
+			///
+			/// # Errors
+			/// If not enough bytes remain after the cursor, returns an [`OutOfBoundsError`]
+			/// describing where the write would have ended. In this case, nothing is written, and
+			/// the cursor will not be moved.
+			pub fn $le(&mut self, value: $ty) -> Result<(), OutOfBoundsError> {
+				self.overwrite_bytes(&value.to_le_bytes())
+			}
+
+			#[doc = concat!("Overwrites the bytes at the cursor with `value` as a big-endian `", stringify!($ty), "`, then moves the cursor forwards past them.")]
+			///
+			/// # Errors
+			/// If not enough bytes remain after the cursor, returns an [`OutOfBoundsError`]
+			/// describing where the write would have ended. In this case, nothing is written, and
+			/// the cursor will not be moved.
+			pub fn $be(&mut self, value: $ty) -> Result<(), OutOfBoundsError> {
+				self.overwrite_bytes(&value.to_be_bytes())
+			}
+		)*
+	};
+}
+
+impl<Tape: IndexableCollection<Item = u8>> CollectionCursor<Tape> {
+	/// Reads a `u8` from the byte at the cursor, then moves the cursor forwards past it.
+	///
+	/// # Errors
+	/// If the cursor is not pointing at a byte, returns [`NotEnoughItems`]. In this case, the
+	/// cursor will not be moved.
+	pub fn read_u8(&mut self) -> Result<u8, NotEnoughItems> {
+		self.next_item().copied().ok_or(NotEnoughItems {
+			requested: 1,
+			available: 0,
+		})
+	}
+
+	/// Reads an `i8` from the byte at the cursor, then moves the cursor forwards past it.
+	///
+	/// # Errors
+	/// If the cursor is not pointing at a byte, returns [`NotEnoughItems`]. In this case, the
+	/// cursor will not be moved.
+	pub fn read_i8(&mut self) -> Result<i8, NotEnoughItems> {
+		self.read_u8().map(u8::cast_signed)
+	}
+
+	/// Reads an unsigned LEB128 variable-length integer from the bytes at the cursor, then moves
+	/// the cursor forwards past them.
+	///
+	/// # Errors
+	/// Returns [`VarintError::UnexpectedEnd`] if the collection ends before the final byte of the
+	/// integer, or [`VarintError::Overflow`] if the integer doesn't fit within a `u64`. In either
+	/// case, the cursor will not be moved.
+	pub fn read_varint_u64(&mut self) -> Result<u64, VarintError> {
+		let mut value = 0_u64;
+
+		for (index, shift) in (self.pos..).zip((0..u64::BITS).step_by(7)) {
+			let byte = *self
+				.inner
+				.get_item(index)
+				.ok_or(VarintError::UnexpectedEnd)?;
+			let bits = u64::from(byte & 0x7F);
+
+			// The last byte may only hold the bits that remain within a `u64`.
+			if bits
+				.checked_shl(shift)
+				.is_none_or(|shifted| shifted >> shift != bits)
+			{
+				return Err(VarintError::Overflow);
+			}
+			value |= bits << shift;
+
+			if byte & 0x80 == 0 {
+				self.record_reads(index + 1 - self.pos);
+				self.pos = index + 1;
+				return Ok(value);
+			}
+		}
+
+		Err(VarintError::Overflow)
+	}
+
+	/// Reads a zigzag-encoded signed LEB128 variable-length integer from the bytes at the cursor,
+	/// then moves the cursor forwards past them.
+	///
+	/// # Errors
+	/// Returns [`VarintError::UnexpectedEnd`] if the collection ends before the final byte of the
+	/// integer, or [`VarintError::Overflow`] if the integer doesn't fit within an `i64`. In either
+	/// case, the cursor will not be moved.
+	pub fn read_varint_i64(&mut self) -> Result<i64, VarintError> {
+		let zigzag = self.read_varint_u64()?;
+		Ok((zigzag >> 1).cast_signed() ^ -(zigzag & 1).cast_signed())
+	}
+
+	/// Decodes the UTF-8 encoded `char` at the cursor, then moves the cursor forwards past its
+	/// bytes.
+	///
+	/// # Errors
+	/// Returns a [`Utf8CursorError`] if the bytes at the cursor are not a complete, valid UTF-8
+	/// sequence. In this case, the cursor will not be moved.
+	pub fn next_char(&mut self) -> Result<char, Utf8CursorError> {
+		let c = self.peek_char()?;
+		self.pos += c.len_utf8();
+		self.record_reads(c.len_utf8());
+		Ok(c)
+	}
+
+	/// Decodes the UTF-8 encoded `char` at the cursor, without moving the cursor.
+	///
+	/// # Errors
+	/// Returns a [`Utf8CursorError`] if the bytes at the cursor are not a complete, valid UTF-8
+	/// sequence.
+	pub fn peek_char(&self) -> Result<char, Utf8CursorError> {
+		let first = *self
+			.inner
+			.get_item(self.pos)
+			.ok_or(Utf8CursorError::EndOfCollection)?;
+		let width = match first {
+			0x00..=0x7F => 1,
+			0xC2..=0xDF => 2,
+			0xE0..=0xEF => 3,
+			0xF0..=0xF4 => 4,
+			_ => return Err(Utf8CursorError::Invalid),
+		};
+
+		let mut bytes = [first, 0, 0, 0];
+		let mut available = 1;
+		while available < width
+			&& let Some(&byte) = self.inner.get_item(self.pos + available)
+		{
+			bytes[available] = byte;
+			available += 1;
+		}
+
+		match core::str::from_utf8(&bytes[..available]) {
+			Ok(s) => s.chars().next().ok_or(Utf8CursorError::Invalid),
+			Err(error) if error.error_len().is_none() => Err(Utf8CursorError::Incomplete),
+			Err(_) => Err(Utf8CursorError::Invalid),
+		}
+	}
+
+	/// Moves the cursor forwards past the next `\n` byte, starting with the byte pointed at by the
+	/// cursor. If no `\n` byte exists, the cursor is moved to one index past the last byte.
+	///
+	/// Returns the range of indices the cursor moved past, including the `\n` byte if one was
+	/// found. This is equivalent to `self.read_until_span(&b'\n')`.
+	pub fn read_line_span(&mut self) -> Range<usize> {
+		self.read_until_span(&b'\n')
+	}
+
+	read_number! {
+		u16: read_u16_le, read_u16_be;
+		u32: read_u32_le, read_u32_be;
+		u64: read_u64_le, read_u64_be;
+		u128: read_u128_le, read_u128_be;
+		i16: read_i16_le, read_i16_be;
+		i32: read_i32_le, read_i32_be;
+		i64: read_i64_le, read_i64_be;
+		i128: read_i128_le, read_i128_be;
+		f32: read_f32_le, read_f32_be;
+		f64: read_f64_le, read_f64_be;
+	}
+}
+
+impl<Tape: IndexableCollectionMut<Item = u8>> CollectionCursor<Tape> {
+	/// Overwrites the bytes at the cursor with `bytes`, then moves the cursor forwards past them,
+	/// but only if every byte fits before the end of the collection.
+	fn overwrite_bytes(&mut self, bytes: &[u8]) -> Result<(), OutOfBoundsError> {
+		self.seek_target(self.pos, SeekFrom::Current(bytes.len().cast_signed()))?;
+		self.write_items(bytes);
+		Ok(())
+	}
+
+	/// Overwrites the byte at the cursor with `value`, then moves the cursor forwards past it.
+	/// Unlike [`Self::write_u8()`], this never resizes the collection, so it can be used with
+	/// collections of a fixed size, such as arrays.
+	///
+	/// # Errors
+	/// If the cursor is not pointing at a byte, returns an [`OutOfBoundsError`]. In this case, the
+	/// cursor will not be moved.
+	pub fn overwrite_u8(&mut self, value: u8) -> Result<(), OutOfBoundsError> {
+		self.overwrite_bytes(&[value])
+	}
+
+	/// Overwrites the byte at the cursor with `value`, then moves the cursor forwards past it.
+	/// Unlike [`Self::write_i8()`], this never resizes the collection, so it can be used with
+	/// collections of a fixed size, such as arrays.
+	///
+	/// # Errors
+	/// If the cursor is not pointing at a byte, returns an [`OutOfBoundsError`]. In this case, the
+	/// cursor will not be moved.
+	pub fn overwrite_i8(&mut self, value: i8) -> Result<(), OutOfBoundsError> {
+		self.overwrite_u8(value.cast_unsigned())
+	}
+
+	overwrite_number! {
+		u16: overwrite_u16_le, overwrite_u16_be;
+		u32: overwrite_u32_le, overwrite_u32_be;
+		u64: overwrite_u64_le, overwrite_u64_be;
+		u128: overwrite_u128_le, overwrite_u128_be;
+		i16: overwrite_i16_le, overwrite_i16_be;
+		i32: overwrite_i32_le, overwrite_i32_be;
+		i64: overwrite_i64_le, overwrite_i64_be;
+		i128: overwrite_i128_le, overwrite_i128_be;
+		f32: overwrite_f32_le, overwrite_f32_be;
+		f64: overwrite_f64_le, overwrite_f64_be;
+	}
+}
+
+impl<Tape: IndexableCollectionResizable<Item = u8>> CollectionCursor<Tape> {
+	/// Writes `src` at the cursor, then moves the cursor forwards past the written bytes. Existing
+	/// bytes from the cursor onwards are overwritten, and the rest are appended once the end of
+	/// the collection is reached.
+	pub(crate) fn write_appending(&mut self, src: &[u8]) {
+		let overwritten = self.write_items(src);
+		for &byte in &src[overwritten..] {
+			self.inner.insert_item(self.pos, byte);
+			self.shift_saved_positions_for_insert(self.pos, 1);
+			self.pos += 1;
+		}
+	}
+
+	/// Writes as much of `src` at the cursor as fits, in the same way as
+	/// [`Self::write_appending()`], and returns the number of bytes written. Bytes are only
+	/// appended while the collection has
+	/// [remaining capacity](IndexableCollectionResizable::remaining_capacity) for them.
+	#[cfg(any(feature = "std", feature = "embedded-io"))]
+	pub(crate) fn write_within_capacity(&mut self, src: &[u8]) -> usize {
+		let overwritable = self.inner.len().saturating_sub(self.pos);
+		let room = match self.inner.remaining_capacity() {
+			Some(remaining) => overwritable.saturating_add(remaining),
+			None => src.len(),
+		};
+
+		let written = src.len().min(room);
+		self.write_appending(&src[..written]);
+		written
+	}
+
+	/// Writes `value` at the cursor, then moves the cursor forwards past it.
+	///
+	/// If the cursor is one index past the last byte, `value` is appended to the collection
+	/// instead of overwriting a byte.
+	///
+	/// # Panics
+	/// Panics if an insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if the collection has a fixed capacity which would
+	/// be exceeded.
+	pub fn write_u8(&mut self, value: u8) {
+		self.write_appending(&[value]);
+	}
+
+	/// Writes `value` at the cursor, then moves the cursor forwards past it.
+	///
+	/// If the cursor is one index past the last byte, `value` is appended to the collection
+	/// instead of overwriting a byte.
+	///
+	/// # Panics
+	/// Panics if an insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if the collection has a fixed capacity which would
+	/// be exceeded.
+	pub fn write_i8(&mut self, value: i8) {
+		self.write_u8(value.cast_unsigned());
+	}
+
+	/// Writes `value` as an unsigned LEB128 variable-length integer at the cursor, then moves the
+	/// cursor forwards past the written bytes.
+	///
+	/// Existing bytes from the cursor onwards are overwritten. Once the end of the collection is
+	/// reached, the remaining bytes are appended to the collection.
+	///
+	/// # Panics
+	/// Panics if an insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if the collection has a fixed capacity which would
+	/// be exceeded.
+	pub fn write_varint_u64(&mut self, mut value: u64) {
+		loop {
+			// Truncation is intended, as only the low seven bits are written per byte.
+			let byte = (value & 0x7F) as u8;
+			value >>= 7;
+
+			if value == 0 {
+				self.write_u8(byte);
+				return;
+			}
+			self.write_u8(byte | 0x80);
+		}
+	}
+
+	/// Writes `value` as a zigzag-encoded signed LEB128 variable-length integer at the cursor,
+	/// then moves the cursor forwards past the written bytes.
+	///
+	/// Existing bytes from the cursor onwards are overwritten. Once the end of the collection is
+	/// reached, the remaining bytes are appended to the collection.
+	///
+	/// # Panics
+	/// Panics if an insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if the collection has a fixed capacity which would
+	/// be exceeded.
+	pub fn write_varint_i64(&mut self, value: i64) {
+		self.write_varint_u64(((value << 1) ^ (value >> 63)).cast_unsigned());
+	}
+
+	write_number! {
+		u16: write_u16_le, write_u16_be;
+		u32: write_u32_le, write_u32_be;
+		u64: write_u64_le, write_u64_be;
+		u128: write_u128_le, write_u128_be;
+		i16: write_i16_le, write_i16_be;
+		i32: write_i32_le, write_i32_be;
+		i64: write_i64_le, write_i64_be;
+		i128: write_i128_le, write_i128_be;
+		f32: write_f32_le, write_f32_be;
+		f64: write_f64_le, write_f64_be;
+	}
+}
+
+#[cfg(test)]
+mod byte_ops_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use crate::{CollectionCursor, Utf8CursorError, VarintError};
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn read_numbers() {
+		let mut cursor = CollectionCursor::new([
+			0xFF, 0x01, 0x02, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x00, 0x00, 0x80,
+			0x3F,
+		]);
+
+		assert_eq!(cursor.read_u8(), Ok(0xFF));
+		cursor.seek_to_start();
+		assert_eq!(cursor.read_i8(), Ok(-1));
+		assert_eq!(cursor.read_u16_le(), Ok(0x0201));
+		cursor.seek_relative(-2).unwrap();
+		assert_eq!(cursor.read_u16_be(), Ok(0x0102));
+		assert_eq!(cursor.position(), 3, "should advance past the bytes read");
+
+		assert_eq!(cursor.read_u64_be(), Ok(0x0102_0304_0506_0708));
+		cursor.seek_relative(-8).unwrap();
+		assert_eq!(cursor.read_i32_le(), Ok(0x0403_0201));
+		cursor.seek_relative(4).unwrap();
+		assert_eq!(cursor.read_f32_le(), Ok(1.0));
+		assert_eq!(cursor.position(), 15);
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn read_numbers_short() {
+		let mut cursor = CollectionCursor::new([0x01, 0x02, 0x03]);
+		cursor.seek_forward_one();
+
+		let error = cursor.read_u32_le().expect_err("only two bytes remain");
+		assert_eq!(error.requested(), 4);
+		assert_eq!(error.available(), 2);
+		assert_eq!(
+			cursor.position(),
+			1,
+			"shouldn't move the cursor on a short read"
+		);
+
+		assert_eq!(cursor.read_u16_le(), Ok(0x0302));
+		assert!(cursor.read_u8().is_err(), "should fail at the end");
+	}
+
+	#[test]
+	fn write_numbers() {
+		let mut cursor = CollectionCursor::new(Vec::from([0xAA; 4]));
+		cursor.seek_forward_one();
+
+		cursor.write_u16_be(0x0102);
+		assert_eq!(
+			cursor.get_ref(),
+			&[0xAA, 0x01, 0x02, 0xAA],
+			"should overwrite"
+		);
+		assert_eq!(
+			cursor.position(),
+			3,
+			"should advance past the bytes written"
+		);
+
+		cursor.write_u32_le(0x0605_0403);
+		assert_eq!(
+			cursor.get_ref(),
+			&[0xAA, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06],
+			"should append past the end"
+		);
+		assert_eq!(cursor.position(), 7);
+
+		cursor.write_i8(-1);
+		cursor.write_f64_be(2.5);
+		cursor.seek_relative(-9).unwrap();
+		assert_eq!(
+			cursor.read_i8(),
+			Ok(-1),
+			"should round-trip with the readers"
+		);
+		assert_eq!(cursor.read_f64_be(), Ok(2.5));
+		assert!(cursor.is_cursor_at_end());
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn overwrite_numbers() {
+		let mut cursor = CollectionCursor::new([0xAA; 4]);
+		cursor.seek_forward_one();
+
+		assert_eq!(cursor.overwrite_u16_be(0x0102), Ok(()));
+		assert_eq!(cursor.get_ref(), &[0xAA, 0x01, 0x02, 0xAA]);
+		assert_eq!(
+			cursor.position(),
+			3,
+			"should advance past the bytes written"
+		);
+
+		let error = cursor
+			.overwrite_u32_le(0x0605_0403)
+			.expect_err("only one byte remains");
+		assert_eq!(error.target(), Some(7));
+		assert_eq!(error.collection_len(), 4);
+		assert_eq!(
+			cursor.get_ref(),
+			&[0xAA, 0x01, 0x02, 0xAA],
+			"shouldn't write anything when out of room"
+		);
+		assert_eq!(cursor.position(), 3, "shouldn't move when out of room");
+
+		assert_eq!(cursor.overwrite_i8(-1), Ok(()));
+		assert!(
+			cursor.overwrite_u8(0).is_err(),
+			"shouldn't append past the end"
+		);
+		cursor.seek_to_start();
+		assert_eq!(cursor.overwrite_f32_le(1.0), Ok(()));
+		cursor.seek_to_start();
+		assert_eq!(
+			cursor.read_f32_le(),
+			Ok(1.0),
+			"should round-trip with the readers"
+		);
+	}
+
+	#[test]
+	fn varint() {
+		let mut cursor = CollectionCursor::new(Vec::new());
+		for value in [0, 1, 127, 128, 300, u64::MAX] {
+			cursor.write_varint_u64(value);
+		}
+		for value in [0, -1, 1, -64, 64, i64::MIN, i64::MAX] {
+			cursor.write_varint_i64(value);
+		}
+		assert_eq!(
+			cursor.get_ref()[..6],
+			[0x00, 0x01, 0x7F, 0x80, 0x01, 0xAC],
+			"should encode as LEB128"
+		);
+
+		cursor.seek_to_start();
+		for value in [0, 1, 127, 128, 300, u64::MAX] {
+			assert_eq!(cursor.read_varint_u64(), Ok(value));
+		}
+		for value in [0, -1, 1, -64, 64, i64::MIN, i64::MAX] {
+			assert_eq!(cursor.read_varint_i64(), Ok(value));
+		}
+		assert!(cursor.is_cursor_at_end());
+	}
+
+	#[test]
+	fn varint_errors() {
+		let mut cursor = CollectionCursor::new(Vec::from([0x80, 0x80]));
+		assert_eq!(cursor.read_varint_u64(), Err(VarintError::UnexpectedEnd));
+		assert_eq!(
+			cursor.position(),
+			0,
+			"shouldn't move the cursor when failing"
+		);
+
+		let mut cursor = CollectionCursor::new(Vec::from([
+			0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02,
+		]));
+		assert_eq!(
+			cursor.read_varint_u64(),
+			Err(VarintError::Overflow),
+			"should reject bits past the 64th"
+		);
+
+		let mut cursor = CollectionCursor::new(Vec::from([0x80; 11]));
+		assert_eq!(
+			cursor.read_varint_u64(),
+			Err(VarintError::Overflow),
+			"should reject more than ten bytes"
+		);
+		assert_eq!(cursor.position(), 0);
+	}
+
+	#[test]
+	fn next_char() {
+		let mut cursor = CollectionCursor::new(Vec::from("aé€😀".as_bytes()));
+
+		assert_eq!(cursor.peek_char(), Ok('a'));
+		assert_eq!(cursor.position(), 0, "peeking shouldn't move the cursor");
+		assert_eq!(cursor.next_char(), Ok('a'));
+		assert_eq!(cursor.next_char(), Ok('é'));
+		assert_eq!(cursor.position(), 3, "should advance by the encoded length");
+		assert_eq!(cursor.next_char(), Ok('€'));
+		assert_eq!(cursor.next_char(), Ok('😀'));
+		assert_eq!(cursor.next_char(), Err(Utf8CursorError::EndOfCollection));
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn next_char_errors() {
+		let mut cursor = CollectionCursor::new([0xE2, 0x82]);
+		assert_eq!(
+			cursor.next_char(),
+			Err(Utf8CursorError::Incomplete),
+			"should detect a truncated sequence"
+		);
+		assert_eq!(
+			cursor.position(),
+			0,
+			"shouldn't move the cursor when failing"
+		);
+
+		for invalid in [[0x80, 0x41], [0xC0, 0x80], [0xE2, 0x41], [0xED, 0xA0]] {
+			let mut cursor = CollectionCursor::new(invalid);
+			assert_eq!(
+				cursor.next_char(),
+				Err(Utf8CursorError::Invalid),
+				"should reject {invalid:02X?}"
+			);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn read_line_span() {
+		let mut cursor = CollectionCursor::new(*b"one\ntwo\n\nlast");
+
+		assert_eq!(cursor.read_line_span(), 0..4, "should include the newline");
+		assert_eq!(cursor.read_line_span(), 4..8);
+		assert_eq!(cursor.read_line_span(), 8..9, "should handle empty lines");
+		assert_eq!(
+			cursor.read_line_span(),
+			9..13,
+			"should consume the rest without a trailing newline"
+		);
+		assert_eq!(cursor.read_line_span(), 13..13);
+	}
+}
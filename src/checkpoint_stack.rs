@@ -0,0 +1,121 @@
+use crate::{CollectionCursor, IndexableCollection};
+
+impl<Tape> CollectionCursor<Tape> {
+	/// Pushes the cursor's current position onto the checkpoint stack, beginning a new nested
+	/// region which can later be committed with [`Self::pop_checkpoint()`] or abandoned with
+	/// [`Self::rollback_to_checkpoint()`].
+	///
+	/// The checkpoint stack is separate from the single checkpoint saved by
+	/// [`Self::save_checkpoint()`].
+	///
+	/// Like that checkpoint, the checkpoints on the stack are shifted when items are inserted or
+	/// removed through the cursor's own methods, so that they keep pointing at the same items.
+	pub fn push_checkpoint(&mut self) {
+		#[cfg(feature = "debug-invariants")]
+		self.before_saving_position();
+
+		self.checkpoint_stack.push(self.pos);
+	}
+
+	/// Removes the most recently pushed checkpoint from the checkpoint stack, without moving the
+	/// cursor. This commits the region begun by the matching [`Self::push_checkpoint()`].
+	///
+	/// Returns the removed checkpoint, or `None` if the checkpoint stack is empty.
+	pub fn pop_checkpoint(&mut self) -> Option<usize> {
+		self.checkpoint_stack.pop()
+	}
+
+	/// Returns the number of checkpoints on the checkpoint stack.
+	pub fn checkpoint_depth(&self) -> usize {
+		self.checkpoint_stack.len()
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape> {
+	/// Removes the most recently pushed checkpoint from the checkpoint stack, and moves the cursor
+	/// back to it. This abandons the region begun by the matching [`Self::push_checkpoint()`].
+	///
+	/// If the collection has shrunk since the checkpoint was pushed, the cursor is instead moved to
+	/// one index past the last item.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the new position of the cursor, or `None` if the
+	/// checkpoint stack is empty. In the latter case, the cursor will not be moved.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if the collection was edited outside of the
+	/// cursor since the position was saved (such as through [`Self::get_mut()`]), and the position
+	/// is still within the bounds of the collection, as it may no longer point at the same item.
+	/// A position past the end of the collection is clamped as described above instead.
+	pub fn rollback_to_checkpoint(&mut self) -> Option<usize> {
+		let checkpoint = self.checkpoint_stack.pop()?;
+		#[cfg(feature = "debug-invariants")]
+		self.assert_saved_position_valid(checkpoint);
+
+		self.pos = checkpoint.min(self.inner.len());
+		Some(self.pos)
+	}
+}
+
+#[cfg(test)]
+mod checkpoint_stack_tests {
+	extern crate alloc;
+
+	use super::*;
+	use alloc::vec::Vec;
+
+	#[test]
+	fn nested_checkpoints() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+
+		cursor.push_checkpoint();
+		cursor.seek_relative(2).unwrap();
+		cursor.push_checkpoint();
+		cursor.seek_relative(2).unwrap();
+		assert_eq!(cursor.checkpoint_depth(), 2);
+
+		assert_eq!(
+			cursor.rollback_to_checkpoint(),
+			Some(2),
+			"should return to the innermost checkpoint"
+		);
+		assert_eq!(cursor.position(), 2);
+		assert_eq!(cursor.checkpoint_depth(), 1);
+
+		cursor.push_checkpoint();
+		cursor.seek_relative(3).unwrap();
+		assert_eq!(cursor.pop_checkpoint(), Some(2));
+		assert_eq!(cursor.position(), 5, "shouldn't move when committing");
+
+		assert_eq!(cursor.rollback_to_checkpoint(), Some(0));
+		assert_eq!(cursor.rollback_to_checkpoint(), None);
+		assert_eq!(cursor.pop_checkpoint(), None);
+		assert_eq!(cursor.position(), 0);
+	}
+
+	#[test]
+	fn rollback_clamps() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_relative(5).unwrap();
+		cursor.push_checkpoint();
+
+		cursor.get_mut().truncate(3);
+		cursor.clamp_to_end();
+		assert_eq!(
+			cursor.rollback_to_checkpoint(),
+			Some(3),
+			"should clamp to the end of a shrunken collection"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "debug-invariants")]
+	#[should_panic = "edited outside of the cursor"]
+	fn rollback_after_untracked_edit() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_relative(2).unwrap();
+		cursor.push_checkpoint();
+
+		cursor.get_mut().truncate(3);
+		cursor.rollback_to_checkpoint();
+	}
+}
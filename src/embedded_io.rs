@@ -0,0 +1,256 @@
+use embedded_io::{ErrorKind, ErrorType, Read, Seek, Write};
+
+use crate::{CollectionCursor, IndexableCollection, IndexableCollectionResizable, SeekFrom};
+
+impl<Tape: IndexableCollection<Item = u8>> ErrorType for CollectionCursor<Tape> {
+	type Error = ErrorKind;
+}
+
+impl<Tape: IndexableCollection<Item = u8>> Read for CollectionCursor<Tape> {
+	/// Copies bytes starting at the cursor into `buf`, then moves the cursor forwards past the
+	/// copied bytes. This is equivalent to [`CollectionCursor::read_items()`].
+	///
+	/// This never returns an error. Once the cursor reaches the end of the collection, `Ok(0)` is
+	/// returned.
+	fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+		Ok(self.read_items(buf))
+	}
+}
+
+impl<Tape: IndexableCollectionResizable<Item = u8>> Write for CollectionCursor<Tape> {
+	/// Writes `buf` at the cursor, then moves the cursor forwards past the written bytes.
+	///
+	/// Existing bytes from the cursor onwards are overwritten. Once the end of the collection is
+	/// reached, the rest of `buf` is appended to the collection, for as long as the collection has
+	/// [remaining capacity](IndexableCollectionResizable::remaining_capacity). Returns the number
+	/// of bytes written, which is less than `buf.len()` if the collection filled up.
+	///
+	/// # Errors
+	/// Returns [`ErrorKind::WriteZero`] if `buf` isn't empty, but the collection is full.
+	fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+		short_write_result(buf, self.write_within_capacity(buf))
+	}
+
+	/// Does nothing, as writes are applied to the collection immediately.
+	fn flush(&mut self) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
+/// Writing to cursors over fixed-size buffers only overwrites bytes, as those buffers can't grow.
+#[cfg(feature = "core")]
+mod overwrite_only {
+	use embedded_io::Write;
+
+	use super::short_write_result;
+	use crate::CollectionCursor;
+
+	impl<const N: usize> Write for CollectionCursor<[u8; N]> {
+		/// Overwrites the bytes at the cursor with `buf`, then moves the cursor forwards past the
+		/// written bytes. This is equivalent to [`CollectionCursor::write_items()`].
+		///
+		/// Returns the number of bytes written, which is less than `buf.len()` if the end of the
+		/// array was reached.
+		///
+		/// # Errors
+		/// Returns [`ErrorKind::WriteZero`](embedded_io::ErrorKind::WriteZero) if `buf` isn't
+		/// empty, but the cursor is at the end of the array.
+		fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+			short_write_result(buf, self.write_items(buf))
+		}
+
+		/// Does nothing, as writes are applied to the array immediately.
+		fn flush(&mut self) -> Result<(), Self::Error> {
+			Ok(())
+		}
+	}
+
+	impl Write for CollectionCursor<&mut [u8]> {
+		/// Overwrites the bytes at the cursor with `buf`, then moves the cursor forwards past the
+		/// written bytes. This is equivalent to [`CollectionCursor::write_items()`].
+		///
+		/// Returns the number of bytes written, which is less than `buf.len()` if the end of the
+		/// slice was reached.
+		///
+		/// # Errors
+		/// Returns [`ErrorKind::WriteZero`](embedded_io::ErrorKind::WriteZero) if `buf` isn't
+		/// empty, but the cursor is at the end of the slice.
+		fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+			short_write_result(buf, self.write_items(buf))
+		}
+
+		/// Does nothing, as writes are applied to the slice immediately.
+		fn flush(&mut self) -> Result<(), Self::Error> {
+			Ok(())
+		}
+	}
+}
+
+/// Turns the number of bytes written from `buf` into the result of [`Write::write()`], which must
+/// not be `Ok(0)` unless `buf` is empty.
+fn short_write_result(buf: &[u8], written: usize) -> Result<usize, ErrorKind> {
+	if written == 0 && !buf.is_empty() {
+		Err(ErrorKind::WriteZero)
+	} else {
+		Ok(written)
+	}
+}
+
+impl<Tape: IndexableCollection<Item = u8>> Seek for CollectionCursor<Tape> {
+	/// Moves the cursor to a new index, in the same way as [`CollectionCursor::seek()`] (including
+	/// respecting the cursor's [`SeekPolicy`](crate::SeekPolicy)).
+	///
+	/// Note that calling `cursor.seek(...)` will resolve to [`CollectionCursor::seek()`], which
+	/// takes the crate's own [`SeekFrom`]. To call this method, use `Seek::seek(&mut cursor, ...)`.
+	///
+	/// # Errors
+	/// Returns [`ErrorKind::InvalidInput`] if the offset within `pos` doesn't fit within a
+	/// `usize`/`isize`, or if [`CollectionCursor::seek()`] returns an error.
+	fn seek(&mut self, pos: embedded_io::SeekFrom) -> Result<u64, Self::Error> {
+		let seek_from = SeekFrom::try_from(pos).map_err(|_| ErrorKind::InvalidInput)?;
+		let new_pos =
+			CollectionCursor::seek(self, seek_from).map_err(|_| ErrorKind::InvalidInput)?;
+
+		// `usize` is at most 64 bits wide on every platform Rust supports.
+		Ok(new_pos as u64)
+	}
+}
+
+impl_seek_from_conversions!(embedded_io, "an `embedded_io::SeekFrom`");
+
+#[cfg(test)]
+mod embedded_io_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use embedded_io::{ErrorKind, Read, Seek, Write};
+
+	use crate::{CollectionCursor, SeekFrom};
+
+	#[test]
+	fn read() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"hello world"));
+		cursor.seek_forward_one();
+
+		let mut buf = [0; 4];
+		assert_eq!(cursor.read(&mut buf), Ok(4));
+		assert_eq!(&buf, b"ello", "should read from the cursor");
+		assert_eq!(cursor.position(), 5, "should advance past the bytes read");
+
+		let mut buf = [0; 6];
+		cursor.read_exact(&mut buf).unwrap();
+		assert_eq!(&buf, b" world");
+		assert_eq!(
+			cursor.read(&mut buf),
+			Ok(0),
+			"should read nothing at the end"
+		);
+	}
+
+	#[test]
+	fn write() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"hello"));
+		cursor.seek_forward_one();
+
+		assert_eq!(cursor.write(b"ipp"), Ok(3));
+		assert_eq!(
+			cursor.get_ref(),
+			b"hippo",
+			"should overwrite from the cursor"
+		);
+		assert_eq!(
+			cursor.position(),
+			4,
+			"should advance past the bytes written"
+		);
+
+		cursor.write_all(b"opotamus").unwrap();
+		assert_eq!(
+			cursor.get_ref(),
+			b"hippopotamus",
+			"should append once past the end"
+		);
+		assert_eq!(cursor.flush(), Ok(()));
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn write_overwrite_only() {
+		let mut cursor = CollectionCursor::new([0; 4]);
+		cursor.seek_forward_one();
+
+		assert_eq!(
+			cursor.write(b"abcdef"),
+			Ok(3),
+			"should stop at the end of the array"
+		);
+		assert_eq!(cursor.get_ref(), &[0, b'a', b'b', b'c']);
+		assert_eq!(
+			cursor.write(b"d"),
+			Err(ErrorKind::WriteZero),
+			"should report a full array"
+		);
+		assert_eq!(cursor.write(b""), Ok(0));
+
+		let mut backing = [0; 3];
+		let mut cursor = CollectionCursor::new(&mut backing[..]);
+		assert_eq!(
+			cursor.write_all(b"xyz!"),
+			Err(ErrorKind::WriteZero),
+			"should fail to write past the end of the slice"
+		);
+		assert_eq!(backing, *b"xyz");
+	}
+
+	#[test]
+	#[cfg(feature = "arrayvec")]
+	fn write_fixed_capacity() {
+		let mut cursor = CollectionCursor::new(arrayvec::ArrayVec::<u8, 4>::from_iter(*b"ab"));
+		cursor.seek_forward_one();
+
+		assert_eq!(
+			cursor.write(b"xyzw"),
+			Ok(3),
+			"should overwrite, then append until the capacity is reached"
+		);
+		assert_eq!(cursor.get_ref().as_slice(), b"axyz");
+		assert_eq!(
+			cursor.write(b"w"),
+			Err(ErrorKind::WriteZero),
+			"should report a full collection instead of panicking"
+		);
+	}
+
+	#[test]
+	fn seek() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"hello world"));
+
+		assert_eq!(
+			Seek::seek(&mut cursor, embedded_io::SeekFrom::Start(6)),
+			Ok(6)
+		);
+		assert_eq!(
+			Seek::seek(&mut cursor, embedded_io::SeekFrom::Current(-2)),
+			Ok(4)
+		);
+		assert_eq!(
+			Seek::seek(&mut cursor, embedded_io::SeekFrom::End(1)),
+			Err(ErrorKind::InvalidInput),
+			"should fail to seek past the end"
+		);
+		assert_eq!(cursor.position(), 4, "shouldn't move when failing");
+
+		cursor.rewind().unwrap();
+		assert_eq!(cursor.position(), 0);
+
+		assert_eq!(
+			SeekFrom::try_from(embedded_io::SeekFrom::End(-3)),
+			Ok(SeekFrom::End(-3))
+		);
+		assert_eq!(
+			embedded_io::SeekFrom::from(SeekFrom::Current(5)),
+			embedded_io::SeekFrom::Current(5)
+		);
+	}
+}
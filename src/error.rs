@@ -0,0 +1,272 @@
+use core::{error::Error, fmt};
+
+use crate::SeekFrom;
+
+/// The error returned by [`CollectionCursor::check_invariant()`], when the cursor's position is
+/// past the end of its collection.
+///
+/// [`CollectionCursor::check_invariant()`]: crate::CollectionCursor::check_invariant
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct InvariantError {
+	/// The position the cursor was at.
+	pub(crate) position: usize,
+	/// The length of the collection at the time of the check.
+	pub(crate) collection_len: usize,
+}
+
+impl InvariantError {
+	/// Returns the position the cursor was at.
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the length of the collection at the time of the check.
+	pub fn collection_len(&self) -> usize {
+		self.collection_len
+	}
+}
+
+impl fmt::Display for InvariantError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"cursor position {} is past the end of the collection (length {})",
+			self.position, self.collection_len
+		)
+	}
+}
+
+impl Error for InvariantError {}
+
+/// The error returned when attempting to seek outside of the bounds of a collection - that is,
+/// before the beginning, or more than one index past the last item.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct OutOfBoundsError {
+	/// The seek which was attempted.
+	pub(crate) seek_from: SeekFrom,
+	/// The position the seek would have moved the cursor to, or `None` if that position is before
+	/// `0` or after `usize::MAX`.
+	pub(crate) target: Option<usize>,
+	/// The length of the collection at the time of the seek.
+	pub(crate) collection_len: usize,
+}
+
+impl OutOfBoundsError {
+	/// Returns the seek which was attempted.
+	pub fn seek_from(&self) -> SeekFrom {
+		self.seek_from
+	}
+
+	/// Returns the position the seek would have moved the cursor to, or `None` if that position is
+	/// before `0` or after `usize::MAX`.
+	pub fn target(&self) -> Option<usize> {
+		self.target
+	}
+
+	/// Returns the length of the collection at the time of the seek.
+	pub fn collection_len(&self) -> usize {
+		self.collection_len
+	}
+}
+
+impl fmt::Display for OutOfBoundsError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.target {
+			Some(target) => write!(
+				f,
+				"`{:?}` would seek to position {target}, which is past the end of the collection \
+				(length {})",
+				self.seek_from, self.collection_len
+			),
+			None => write!(
+				f,
+				"`{:?}` would seek before the beginning of the collection, or past `usize::MAX` \
+				(length {})",
+				self.seek_from, self.collection_len
+			),
+		}
+	}
+}
+
+impl Error for OutOfBoundsError {}
+
+/// The error returned when fewer items remain after the cursor than were requested.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct NotEnoughItems {
+	/// The number of items which were requested.
+	pub(crate) requested: usize,
+	/// The number of items which remained after the cursor.
+	pub(crate) available: usize,
+}
+
+impl NotEnoughItems {
+	/// Returns the number of items which were requested.
+	pub fn requested(&self) -> usize {
+		self.requested
+	}
+
+	/// Returns the number of items which remained after the cursor.
+	pub fn available(&self) -> usize {
+		self.available
+	}
+}
+
+impl fmt::Display for NotEnoughItems {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"requested {} items, but only {} remain after the cursor",
+			self.requested, self.available
+		)
+	}
+}
+
+impl Error for NotEnoughItems {}
+
+/// The error returned by [`CollectionCursor::expect_item()`] and
+/// [`CollectionCursor::expect_item_cloned()`], when the item at the cursor is not the expected
+/// item.
+///
+/// [`CollectionCursor::expect_item()`]: crate::CollectionCursor::expect_item
+/// [`CollectionCursor::expect_item_cloned()`]: crate::CollectionCursor::expect_item_cloned
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct UnexpectedItem<T> {
+	/// The position the cursor was at.
+	pub(crate) position: usize,
+	/// The item found at the cursor, or `None` if the cursor was not pointing at an item.
+	pub(crate) found: Option<T>,
+}
+
+impl<T> UnexpectedItem<T> {
+	/// Returns the position the cursor was at.
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the item found at the cursor, or `None` if the cursor was not pointing at an item.
+	pub fn found(&self) -> Option<&T> {
+		self.found.as_ref()
+	}
+
+	/// Consumes this error, returning the item found at the cursor, or `None` if the cursor was
+	/// not pointing at an item.
+	pub fn into_found(self) -> Option<T> {
+		self.found
+	}
+}
+
+impl<T: Clone> UnexpectedItem<&T> {
+	/// Clones the item found at the cursor, so that this error no longer borrows from the
+	/// collection.
+	pub fn cloned(self) -> UnexpectedItem<T> {
+		UnexpectedItem {
+			position: self.position,
+			found: self.found.cloned(),
+		}
+	}
+}
+
+impl<T: fmt::Debug> fmt::Display for UnexpectedItem<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.found {
+			Some(found) => write!(
+				f,
+				"found unexpected item `{found:?}` at position {}",
+				self.position
+			),
+			None => write!(
+				f,
+				"expected an item at position {}, but the collection ended",
+				self.position
+			),
+		}
+	}
+}
+
+impl<T: fmt::Debug> Error for UnexpectedItem<T> {}
+
+/// The error returned by [`CollectionCursor::apply_patch()`], when an operation within the patch
+/// targets an index outside of the collection's bounds.
+///
+/// [`CollectionCursor::apply_patch()`]: crate::CollectionCursor::apply_patch
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct PatchError {
+	/// The position of the offending operation within the patch.
+	pub(crate) op: usize,
+	/// The index the operation targets.
+	pub(crate) index: usize,
+	/// The length the collection would have had when the operation was applied.
+	pub(crate) collection_len: usize,
+}
+
+impl PatchError {
+	/// Returns the position of the offending operation within the patch.
+	pub fn op(&self) -> usize {
+		self.op
+	}
+
+	/// Returns the index the operation targets.
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Returns the length the collection would have had when the operation was applied.
+	pub fn collection_len(&self) -> usize {
+		self.collection_len
+	}
+}
+
+impl fmt::Display for PatchError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"patch operation {} targets index {}, which is out of bounds (length {})",
+			self.op, self.index, self.collection_len
+		)
+	}
+}
+
+impl Error for PatchError {}
+
+/// The error returned when a variable-length integer could not be read from the cursor.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum VarintError {
+	/// The end of the collection was reached before the final byte of the integer.
+	UnexpectedEnd,
+	/// The encoded integer is too large to fit within the requested type.
+	Overflow,
+}
+
+impl fmt::Display for VarintError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnexpectedEnd => write!(f, "the collection ended partway through a varint"),
+			Self::Overflow => write!(f, "the varint is too large for the requested type"),
+		}
+	}
+}
+
+impl Error for VarintError {}
+
+/// The error returned when a UTF-8 encoded `char` could not be decoded at the cursor.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Utf8CursorError {
+	/// The cursor is not pointing at a byte.
+	EndOfCollection,
+	/// The collection ended partway through the encoded `char`.
+	Incomplete,
+	/// The bytes at the cursor are not valid UTF-8.
+	Invalid,
+}
+
+impl fmt::Display for Utf8CursorError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::EndOfCollection => write!(f, "the cursor is at the end of the collection"),
+			Self::Incomplete => write!(f, "the collection ended partway through a UTF-8 sequence"),
+			Self::Invalid => write!(f, "the bytes at the cursor are not valid UTF-8"),
+		}
+	}
+}
+
+impl Error for Utf8CursorError {}
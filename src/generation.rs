@@ -0,0 +1,185 @@
+use crate::{CollectionCursor, CursorSpan, IndexableCollection};
+
+/// Counts the structural edits made to a cursor's collection, so that positions saved before an
+/// incompatible edit can be detected when they are used.
+///
+/// This is bookkeeping only, so cursors hold it within an [`Ignored`](crate::ignored::Ignored).
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Generation {
+	/// The number of structural edits made to the collection, whether or not the cursor could
+	/// adjust its saved positions for them.
+	edits: u64,
+	/// The value of `edits` at which the cursor's saved positions were last known to be valid.
+	saved: u64,
+}
+
+impl Generation {
+	/// Creates a new generation counter, for a collection with no edits made to it yet.
+	pub(crate) const fn new() -> Self {
+		Self { edits: 0, saved: 0 }
+	}
+
+	/// Returns the number of structural edits made so far.
+	pub(crate) fn current(&self) -> u64 {
+		self.edits
+	}
+
+	/// Records a structural edit for which the saved positions were adjusted.
+	pub(crate) fn record_tracked_edit(&mut self) {
+		if self.saved == self.edits {
+			self.saved = self.saved.wrapping_add(1);
+		}
+		self.edits = self.edits.wrapping_add(1);
+	}
+
+	/// Records an edit which the cursor could not see, such as through
+	/// [`CollectionCursor::get_mut()`]. The saved positions can no longer be trusted.
+	pub(crate) fn record_untracked_edit(&mut self) {
+		self.edits = self.edits.wrapping_add(1);
+	}
+
+	/// Marks the saved positions as valid. This must only be done while no positions are saved.
+	pub(crate) fn sync_saved(&mut self) {
+		self.saved = self.edits;
+	}
+
+	/// Returns whether the saved positions are still valid.
+	pub(crate) fn saved_positions_valid(&self) -> bool {
+		self.saved == self.edits
+	}
+}
+
+impl<Tape> CollectionCursor<Tape> {
+	/// Returns whether any position is saved in the checkpoint, the checkpoint stack, or the
+	/// marks.
+	fn has_saved_positions(&self) -> bool {
+		#[cfg(feature = "alloc")]
+		if !self.checkpoint_stack.is_empty() || !self.marks.is_empty() {
+			return true;
+		}
+
+		self.checkpoint.is_some()
+	}
+
+	/// Called before a position is saved. If no other positions are saved, any earlier untracked
+	/// edit cannot affect the new position, so the saved positions are marked as valid again.
+	pub(crate) fn before_saving_position(&mut self) {
+		if !self.has_saved_positions() {
+			self.generation.sync_saved();
+		}
+	}
+
+	/// Panics if the collection was edited in a way the cursor could not track since the saved
+	/// positions were saved.
+	pub(crate) fn assert_saved_positions_valid(&self) {
+		assert!(
+			self.generation.saved_positions_valid(),
+			"the collection was edited outside of the cursor since this position was saved"
+		);
+	}
+
+	/// Panics if the collection was structurally edited since `span` was created.
+	pub(crate) fn assert_span_valid(&self, span: &CursorSpan) {
+		if let Some(generation) = *span.generation {
+			assert!(
+				generation == self.generation.current(),
+				"the collection was structurally edited since this span was created"
+			);
+		}
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape> {
+	/// Panics if `saved` is still within the bounds of the collection, but the collection was
+	/// edited in a way the cursor could not track since it was saved.
+	///
+	/// A position past the end of the collection cannot point at the wrong item, so it is left to
+	/// the caller to clamp it instead.
+	pub(crate) fn assert_saved_position_valid(&self, saved: usize) {
+		if saved <= self.inner.len() {
+			self.assert_saved_positions_valid();
+		}
+	}
+}
+
+#[cfg(test)]
+mod generation_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use crate::CollectionCursor;
+
+	fn test_cursor() -> CollectionCursor<Vec<i32>> {
+		CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]))
+	}
+
+	#[test]
+	fn tracked_edits_keep_saved_positions() {
+		let mut cursor = test_cursor();
+		cursor.seek_relative(3).unwrap();
+		cursor.save_checkpoint();
+
+		cursor.seek_to_start();
+		cursor.insert_item_at_cursor(10);
+		cursor.remove_item_at_cursor();
+		assert_eq!(
+			cursor.restore_checkpoint(),
+			Some(3),
+			"shouldn't panic after edits the cursor adjusted for"
+		);
+	}
+
+	#[test]
+	#[should_panic = "edited outside of the cursor"]
+	fn untracked_edit_invalidates_checkpoint() {
+		let mut cursor = test_cursor();
+		cursor.save_checkpoint();
+		cursor.get_mut().push(6);
+		cursor.restore_checkpoint();
+	}
+
+	#[test]
+	#[should_panic = "edited outside of the cursor"]
+	fn edit_invalidates_checkpoint() {
+		let mut cursor = test_cursor();
+		cursor.save_checkpoint();
+		cursor.edit(|collection| collection[0] = 10);
+		cursor.restore_checkpoint();
+	}
+
+	#[test]
+	#[should_panic = "edited outside of the cursor"]
+	fn get_mut_guarded_resize_invalidates_mark() {
+		let mut cursor = test_cursor();
+		cursor.set_mark("start");
+		cursor.get_mut_guarded().truncate(2);
+		cursor.seek_to_mark("start");
+	}
+
+	#[test]
+	fn saving_after_clearing_resyncs() {
+		let mut cursor = test_cursor();
+		cursor.save_checkpoint();
+		cursor.get_mut().push(6);
+		cursor.clear_checkpoint();
+
+		cursor.seek_to_end();
+		cursor.save_checkpoint();
+		assert_eq!(
+			cursor.restore_checkpoint(),
+			Some(7),
+			"should trust a checkpoint saved after the edit"
+		);
+	}
+
+	#[test]
+	#[should_panic = "structurally edited since this span was created"]
+	fn structural_edit_invalidates_span() {
+		let mut cursor = test_cursor();
+		cursor.seek_relative(2).unwrap();
+		let span = cursor.span_from(0);
+		cursor.insert_item_at_cursor(10);
+		let _ = span.iter(&cursor);
+	}
+}
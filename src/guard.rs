@@ -0,0 +1,233 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::{CollectionCursor, IndexableCollection};
+
+/// Provides mutable access to a cursor's underlying collection, and clamps the cursor back within
+/// the bounds of the collection when dropped.
+///
+/// As the clamping happens on drop, the cursor's invariant is restored even after an early return
+/// or a panic. This struct is created by [`CollectionCursor::get_mut_guarded()`].
+#[derive(Debug)]
+pub struct ClampGuard<'a, Tape: IndexableCollection> {
+	/// The cursor whose collection we're providing access to.
+	cursor: &'a mut CollectionCursor<Tape>,
+}
+
+impl<'a, Tape: IndexableCollection> ClampGuard<'a, Tape> {
+	pub(crate) fn new(cursor: &'a mut CollectionCursor<Tape>) -> Self {
+		Self { cursor }
+	}
+}
+
+impl<Tape: IndexableCollection> Deref for ClampGuard<'_, Tape> {
+	type Target = Tape;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cursor.inner
+	}
+}
+
+impl<Tape: IndexableCollection> DerefMut for ClampGuard<'_, Tape> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		#[cfg(feature = "debug-invariants")]
+		self.cursor.generation.record_untracked_edit();
+
+		&mut self.cursor.inner
+	}
+}
+
+impl<Tape: IndexableCollection> Drop for ClampGuard<'_, Tape> {
+	fn drop(&mut self) {
+		self.cursor.clamp_to_end();
+	}
+}
+
+/// Provides access to a cursor, and moves the cursor back to its original position when dropped,
+/// unless [`Self::commit()`] is called first.
+///
+/// As the position is restored on drop, speculative movement is undone even after an early return
+/// or a panic. This struct is created by [`CollectionCursor::position_guard()`].
+#[derive(Debug)]
+pub struct PositionGuard<'a, Tape: IndexableCollection> {
+	/// The cursor whose position will be restored.
+	cursor: &'a mut CollectionCursor<Tape>,
+	/// The position the cursor was at when this guard was created.
+	start: usize,
+	/// Whether the cursor's new position has been kept, in which case it won't be restored.
+	committed: bool,
+}
+
+impl<'a, Tape: IndexableCollection> PositionGuard<'a, Tape> {
+	pub(crate) fn new(cursor: &'a mut CollectionCursor<Tape>) -> Self {
+		let start = cursor.pos;
+		Self {
+			cursor,
+			start,
+			committed: false,
+		}
+	}
+
+	/// Returns the position the cursor was at when this guard was created.
+	pub fn start(&self) -> usize {
+		self.start
+	}
+
+	/// Consumes this guard, keeping the cursor at its current position.
+	pub fn commit(mut self) {
+		self.committed = true;
+	}
+}
+
+impl<Tape: IndexableCollection> Deref for PositionGuard<'_, Tape> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection> DerefMut for PositionGuard<'_, Tape> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection> Drop for PositionGuard<'_, Tape> {
+	fn drop(&mut self) {
+		if !self.committed {
+			self.cursor.pos = self.start.min(self.cursor.inner.len());
+		}
+	}
+}
+
+#[cfg(test)]
+mod guard_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use crate::CollectionCursor;
+
+	#[test]
+	fn get_mut_guarded() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		collection.seek_to_end();
+
+		{
+			let mut guard = collection.get_mut_guarded();
+			guard.truncate(2);
+			guard.push(6);
+			assert_eq!(
+				*guard,
+				[0, 1, 6],
+				"should give access to the inner collection"
+			);
+		}
+
+		assert_eq!(
+			collection.position(),
+			3,
+			"should clamp the cursor after the guard is dropped"
+		);
+	}
+
+	#[test]
+	#[allow(deprecated, reason = "checks that the old name still works")]
+	fn clamp_guard() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2]));
+		collection.seek_to_end();
+		collection.clamp_guard().pop();
+		assert_eq!(collection.position(), 2);
+	}
+
+	#[test]
+	fn get_mut_guarded_early_return() {
+		fn shrink(collection: &mut CollectionCursor<Vec<i32>>) -> Option<()> {
+			let mut guard = collection.get_mut_guarded();
+			guard.clear();
+			guard.first()?;
+			Some(())
+		}
+
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		collection.seek_to_last_item();
+
+		assert_eq!(shrink(&mut collection), None);
+		assert_eq!(
+			collection.position(),
+			0,
+			"should clamp the cursor after returning early"
+		);
+	}
+
+	#[test]
+	fn get_mut_guarded_panic() {
+		extern crate std;
+
+		use core::panic::AssertUnwindSafe;
+		use std::panic;
+
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		collection.seek_to_end();
+
+		let result = panic::catch_unwind(AssertUnwindSafe(|| {
+			let mut guard = collection.get_mut_guarded();
+			guard.truncate(1);
+			panic!("failed partway through an edit");
+		}));
+		assert!(result.is_err());
+		assert_eq!(
+			collection.position(),
+			1,
+			"should clamp the cursor after a panic"
+		);
+	}
+
+	#[test]
+	fn position_guard() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		collection.seek_relative(1).unwrap();
+
+		{
+			let mut guard = collection.position_guard();
+			guard.seek_relative(3).unwrap();
+			assert_eq!(guard.position(), 4, "should give access to the cursor");
+			assert_eq!(guard.start(), 1);
+		}
+		assert_eq!(
+			collection.position(),
+			1,
+			"should restore the position after the guard is dropped"
+		);
+
+		let mut guard = collection.position_guard();
+		guard.seek_relative(3).unwrap();
+		guard.commit();
+		assert_eq!(
+			collection.position(),
+			4,
+			"shouldn't restore the position after committing"
+		);
+	}
+
+	#[test]
+	fn position_guard_early_return() {
+		fn parse_pair(collection: &mut CollectionCursor<Vec<i32>>) -> Option<(i32, i32)> {
+			let mut guard = collection.position_guard();
+			let first = *guard.next_item()?;
+			let second = *guard.next_item()?;
+			guard.commit();
+			Some((first, second))
+		}
+
+		let mut collection = CollectionCursor::new(Vec::from([0, 1, 2]));
+		assert_eq!(parse_pair(&mut collection), Some((0, 1)));
+		assert_eq!(collection.position(), 2);
+		assert_eq!(parse_pair(&mut collection), None);
+		assert_eq!(
+			collection.position(),
+			2,
+			"should restore the position after returning early"
+		);
+	}
+}
@@ -0,0 +1,49 @@
+use core::{
+	cmp::Ordering,
+	hash::{Hash, Hasher},
+	ops::{Deref, DerefMut},
+};
+
+/// Wraps bookkeeping data which shouldn't affect how its owner is compared or hashed. Every
+/// `Ignored` value is considered equal to every other, so that, for example, two cursors with
+/// equal collections and positions are still equal.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Ignored<T>(pub(crate) T);
+
+impl<T> Deref for Ignored<T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl<T> DerefMut for Ignored<T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.0
+	}
+}
+
+impl<T> PartialEq for Ignored<T> {
+	fn eq(&self, _other: &Self) -> bool {
+		true
+	}
+}
+
+impl<T> Eq for Ignored<T> {}
+
+impl<T> PartialOrd for Ignored<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T> Ord for Ignored<T> {
+	fn cmp(&self, _other: &Self) -> Ordering {
+		Ordering::Equal
+	}
+}
+
+impl<T> Hash for Ignored<T> {
+	fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
@@ -0,0 +1,178 @@
+use core::ops::{Index, IndexMut};
+
+use crate::{IndexableCollection, IndexableCollectionMut};
+
+/// Adapts a collection implementing [`Index<usize>`] into an [`IndexableCollection`], without
+/// needing to write the trait impls by hand.
+///
+/// `Index` alone cannot tell us how many items a collection holds, so the adapter also stores a
+/// function which returns the length of the wrapped collection. For collections which implement
+/// `AsRef<[T]>`, [`IndexAdapter::from_slice_like()`] will provide this function for you.
+///
+/// If the wrapped collection also implements [`IndexMut<usize>`], the adapter will implement
+/// [`IndexableCollectionMut`] as well.
+#[derive(Clone, Copy, Debug)]
+pub struct IndexAdapter<C, L = fn(&C) -> usize> {
+	/// The wrapped collection.
+	inner: C,
+	/// A function which returns the number of items within `inner`.
+	len: L,
+}
+
+impl<C, L: Fn(&C) -> usize> IndexAdapter<C, L> {
+	/// Creates a new `IndexAdapter` wrapping the provided collection. `len` will be called whenever
+	/// the length of the collection is needed.
+	pub fn new(inner: C, len: L) -> Self {
+		Self { inner, len }
+	}
+}
+
+impl<C> IndexAdapter<C> {
+	/// Creates a new `IndexAdapter` wrapping a collection which can be viewed as a slice. The
+	/// length of the collection will be taken from the length of that slice.
+	pub fn from_slice_like<T>(inner: C) -> Self
+	where
+		C: AsRef<[T]>,
+	{
+		Self {
+			inner,
+			len: |inner| AsRef::<[T]>::as_ref(inner).len(),
+		}
+	}
+}
+
+impl<C, L> IndexAdapter<C, L> {
+	/// Gets a reference to the wrapped collection.
+	pub fn get_ref(&self) -> &C {
+		&self.inner
+	}
+
+	/// Gets a mutable reference to the wrapped collection.
+	pub fn get_mut(&mut self) -> &mut C {
+		&mut self.inner
+	}
+
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+}
+
+impl<C, L> IndexableCollection for IndexAdapter<C, L>
+where
+	C: Index<usize>,
+	C::Output: Sized,
+	L: Fn(&C) -> usize,
+{
+	type Item = C::Output;
+
+	fn len(&self) -> usize {
+		(self.len)(&self.inner)
+	}
+
+	fn get_item(&self, index: usize) -> Option<&Self::Item> {
+		(index < self.len()).then(|| &self.inner[index])
+	}
+}
+
+impl<C, L> IndexableCollectionMut for IndexAdapter<C, L>
+where
+	C: IndexMut<usize>,
+	C::Output: Sized,
+	L: Fn(&C) -> usize,
+{
+	fn get_item_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+		(index < self.len()).then(|| &mut self.inner[index])
+	}
+
+	fn set_item(&mut self, index: usize, element: Self::Item) {
+		self.inner[index] = element;
+	}
+}
+
+#[cfg(test)]
+mod index_adapter_tests {
+	use core::ops::{Index, IndexMut};
+
+	use super::IndexAdapter;
+	use crate::{CollectionCursor, IndexableCollection, IndexableCollectionMut};
+
+	/// A user-defined collection which only implements the standard indexing traits.
+	struct SliceWrapper<'a>(&'a mut [i32]);
+
+	impl Index<usize> for SliceWrapper<'_> {
+		type Output = i32;
+
+		fn index(&self, index: usize) -> &Self::Output {
+			&self.0[index]
+		}
+	}
+
+	impl IndexMut<usize> for SliceWrapper<'_> {
+		fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+			&mut self.0[index]
+		}
+	}
+
+	impl AsRef<[i32]> for SliceWrapper<'_> {
+		fn as_ref(&self) -> &[i32] {
+			self.0
+		}
+	}
+
+	#[test]
+	fn from_slice_like() {
+		let mut data = [1, 2, 3];
+		let adapter = IndexAdapter::from_slice_like(SliceWrapper(&mut data));
+
+		assert_eq!(adapter.len(), 3, "should take the length from the slice");
+		assert_eq!(adapter.get_item(0), Some(&1));
+		assert_eq!(adapter.get_item(2), Some(&3));
+		assert_eq!(
+			adapter.get_item(3),
+			None,
+			"should return `None` rather than panic when out-of-bounds"
+		);
+	}
+
+	#[test]
+	fn new_with_len_function() {
+		let mut data = [1, 2, 3];
+		let adapter = IndexAdapter::new(SliceWrapper(&mut data), |_: &SliceWrapper<'_>| 2);
+
+		assert_eq!(adapter.len(), 2, "should take the length from the function");
+		assert_eq!(
+			adapter.get_item(2),
+			None,
+			"should not return items past the length reported by the function"
+		);
+	}
+
+	#[test]
+	fn mutable_access() {
+		let mut data = [1, 2, 3];
+		let mut adapter = IndexAdapter::from_slice_like(SliceWrapper(&mut data));
+
+		adapter.set_item(1, 20);
+		*adapter.get_item_mut(2).unwrap() = 30;
+		assert_eq!(adapter.get_item_mut(3), None);
+
+		assert_eq!(
+			adapter.into_inner().0,
+			&[1, 20, 30],
+			"should write through to the wrapped collection"
+		);
+	}
+
+	#[test]
+	fn within_cursor() {
+		let mut data = [1, 2, 3];
+		let mut cursor =
+			CollectionCursor::new(IndexAdapter::from_slice_like(SliceWrapper(&mut data)));
+
+		cursor.seek_to_last_item();
+		assert_eq!(cursor.get_item_at_cursor(), Some(&3));
+
+		cursor.set_item_at_cursor(6);
+		assert_eq!(cursor.into_inner().into_inner().0, &[1, 2, 6]);
+	}
+}
@@ -0,0 +1,262 @@
+extern crate std;
+
+use std::io;
+
+use crate::{
+	CollectionCursor, ContiguousCollection, IndexableCollection, IndexableCollectionResizable,
+	SeekFrom,
+};
+
+impl<Tape: IndexableCollection<Item = u8>> io::Read for CollectionCursor<Tape> {
+	/// Copies bytes starting at the cursor into `buf`, then moves the cursor forwards past the
+	/// copied bytes. This is equivalent to [`CollectionCursor::read_items()`].
+	///
+	/// This never returns an error. Once the cursor reaches the end of the collection, `Ok(0)` is
+	/// returned.
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		Ok(self.read_items(buf))
+	}
+}
+
+impl<Tape: ContiguousCollection<Item = u8>> io::BufRead for CollectionCursor<Tape> {
+	/// Returns the bytes from the cursor onwards. As the bytes are already in memory, this never
+	/// returns an error.
+	fn fill_buf(&mut self) -> io::Result<&[u8]> {
+		let items = self.inner.as_slice();
+		Ok(&items[self.pos.min(items.len())..])
+	}
+
+	/// Moves the cursor forwards by `amt` bytes, stopping at one index past the last byte.
+	fn consume(&mut self, amt: usize) {
+		let new_pos = self.pos.saturating_add(amt).min(self.inner.len());
+		self.record_reads(new_pos.saturating_sub(self.pos));
+		self.pos = new_pos;
+	}
+}
+
+impl<Tape: IndexableCollectionResizable<Item = u8>> io::Write for CollectionCursor<Tape> {
+	/// Writes `buf` at the cursor, then moves the cursor forwards past the written bytes.
+	///
+	/// Existing bytes from the cursor onwards are overwritten. Once the end of the collection is
+	/// reached, the rest of `buf` is appended to the collection, for as long as the collection has
+	/// [remaining capacity](IndexableCollectionResizable::remaining_capacity). This matches the
+	/// behavior of `std::io::Cursor<Vec<u8>>`.
+	///
+	/// This never returns an error. Returns the number of bytes written, which is less than
+	/// `buf.len()` (possibly `0`) if the collection filled up.
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		Ok(self.write_within_capacity(buf))
+	}
+
+	/// Does nothing, as writes are applied to the collection immediately.
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+impl<Tape: IndexableCollection<Item = u8>> io::Seek for CollectionCursor<Tape> {
+	/// Moves the cursor to a new index, in the same way as [`CollectionCursor::seek()`] (including
+	/// respecting the cursor's [`SeekPolicy`](crate::SeekPolicy)).
+	///
+	/// Unlike `std::io::Cursor`, seeking past the end of the collection is an error (unless the
+	/// cursor's seek policy says otherwise).
+	///
+	/// Note that calling `cursor.seek(...)` will resolve to [`CollectionCursor::seek()`], which
+	/// takes the crate's own [`SeekFrom`]. To call this method, use `Seek::seek(&mut cursor, ...)`.
+	///
+	/// # Errors
+	/// Returns an error of kind [`io::ErrorKind::InvalidInput`] if the offset within `pos` doesn't
+	/// fit within a `usize`/`isize`, or if [`CollectionCursor::seek()`] returns an error.
+	fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+		let seek_from = SeekFrom::try_from(pos)
+			.map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+		let new_pos = CollectionCursor::seek(self, seek_from)
+			.map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+		// `usize` is at most 64 bits wide on every platform Rust supports.
+		Ok(new_pos as u64)
+	}
+}
+
+impl_seek_from_conversions!(io, "a `std::io::SeekFrom`");
+
+#[cfg(test)]
+mod io_tests {
+	extern crate alloc;
+	extern crate std;
+
+	use alloc::{collections::VecDeque, string::String, vec::Vec};
+	use std::io::{self, BufRead, Read, Seek, Write};
+
+	use crate::{CollectionCursor, SeekFrom, SeekPolicy};
+
+	#[test]
+	fn read() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"hello world"));
+		cursor.seek_forward_one();
+
+		let mut buf = [0; 4];
+		assert_eq!(cursor.read(&mut buf).unwrap(), 4);
+		assert_eq!(&buf, b"ello", "should read from the cursor");
+		assert_eq!(cursor.position(), 5, "should advance past the bytes read");
+
+		let mut rest = Vec::new();
+		assert_eq!(cursor.read_to_end(&mut rest).unwrap(), 6);
+		assert_eq!(rest, b" world");
+		assert_eq!(
+			cursor.read(&mut buf).unwrap(),
+			0,
+			"should read nothing at the end"
+		);
+	}
+
+	#[test]
+	fn read_non_contiguous() {
+		let mut deque = VecDeque::from(*b"cd");
+		deque.push_front(b'b');
+		deque.push_front(b'a');
+		let mut cursor = CollectionCursor::new(deque);
+
+		let mut buf = [0; 4];
+		cursor.read_exact(&mut buf).unwrap();
+		assert_eq!(&buf, b"abcd");
+	}
+
+	#[test]
+	fn write() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"hello"));
+		cursor.seek_forward_one();
+
+		assert_eq!(cursor.write(b"ipp").unwrap(), 3);
+		assert_eq!(
+			cursor.get_ref(),
+			b"hippo",
+			"should overwrite from the cursor"
+		);
+		assert_eq!(
+			cursor.position(),
+			4,
+			"should advance past the bytes written"
+		);
+
+		cursor.write_all(b"opotamus").unwrap();
+		assert_eq!(
+			cursor.get_ref(),
+			b"hippopotamus",
+			"should append once past the end"
+		);
+		assert_eq!(cursor.position(), 12);
+
+		cursor.seek_to_end();
+		write!(cursor, "!{}", 1).unwrap();
+		cursor.flush().unwrap();
+		assert_eq!(cursor.get_ref(), b"hippopotamus!1");
+	}
+
+	#[test]
+	#[cfg(feature = "arrayvec")]
+	fn write_fixed_capacity() {
+		let mut cursor = CollectionCursor::new(arrayvec::ArrayVec::<u8, 4>::from_iter(*b"ab"));
+		cursor.seek_forward_one();
+
+		assert_eq!(
+			cursor.write(b"xyzw").unwrap(),
+			3,
+			"should overwrite, then append until the capacity is reached"
+		);
+		assert_eq!(cursor.get_ref().as_slice(), b"axyz");
+		assert_eq!(
+			cursor.write_all(b"w").unwrap_err().kind(),
+			io::ErrorKind::WriteZero,
+			"should report a full collection instead of panicking"
+		);
+	}
+
+	#[test]
+	fn seek() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"hello world"));
+
+		assert_eq!(Seek::seek(&mut cursor, io::SeekFrom::Start(6)).unwrap(), 6);
+		assert_eq!(
+			Seek::seek(&mut cursor, io::SeekFrom::Current(-2)).unwrap(),
+			4
+		);
+		assert_eq!(Seek::seek(&mut cursor, io::SeekFrom::End(-1)).unwrap(), 10);
+		assert_eq!(cursor.stream_position().unwrap(), 10);
+
+		let error = Seek::seek(&mut cursor, io::SeekFrom::End(1))
+			.expect_err("seeking past the end should fail");
+		assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+		assert_eq!(cursor.position(), 10, "shouldn't move when failing");
+
+		cursor.rewind().unwrap();
+		assert_eq!(cursor.position(), 0);
+
+		cursor.set_seek_policy(SeekPolicy::Clamp);
+		assert_eq!(
+			Seek::seek(&mut cursor, io::SeekFrom::End(5)).unwrap(),
+			11,
+			"should respect the seek policy"
+		);
+
+		cursor.set_seek_policy(SeekPolicy::Wrap);
+		assert_eq!(
+			cursor.stream_position().unwrap(),
+			11,
+			"shouldn't wrap when reporting the position one past the last item"
+		);
+	}
+
+	#[test]
+	fn seek_from_conversions() {
+		assert_eq!(
+			SeekFrom::try_from(io::SeekFrom::Current(-3)),
+			Ok(SeekFrom::Current(-3))
+		);
+		assert_eq!(
+			SeekFrom::try_from(io::SeekFrom::Start(7)),
+			Ok(SeekFrom::Start(7))
+		);
+		assert_eq!(io::SeekFrom::from(SeekFrom::End(-2)), io::SeekFrom::End(-2));
+		assert_eq!(
+			io::SeekFrom::from(SeekFrom::Start(5)),
+			io::SeekFrom::Start(5)
+		);
+
+		if usize::BITS < u64::BITS {
+			assert!(
+				SeekFrom::try_from(io::SeekFrom::Start(u64::MAX)).is_err(),
+				"should fail if the offset doesn't fit"
+			);
+		}
+	}
+
+	#[test]
+	fn buf_read() {
+		let mut cursor = CollectionCursor::new(Vec::from(*b"first\nsecond\nthird"));
+		cursor.seek_forward_one();
+
+		assert_eq!(cursor.fill_buf().unwrap(), b"irst\nsecond\nthird");
+		cursor.consume(5);
+		assert_eq!(
+			cursor.position(),
+			6,
+			"should move forwards by the amount consumed"
+		);
+
+		let mut line = String::new();
+		assert_eq!(cursor.read_line(&mut line).unwrap(), 7);
+		assert_eq!(line, "second\n");
+
+		let lines = cursor.lines().collect::<Result<Vec<_>, _>>().unwrap();
+		assert_eq!(lines, ["third"]);
+
+		let mut cursor = CollectionCursor::new(Vec::from(*b"a,b"));
+		let mut buf = Vec::new();
+		cursor.read_until(b',', &mut buf).unwrap();
+		assert_eq!(buf, b"a,");
+		cursor.consume(100);
+		assert_eq!(cursor.position(), 3, "shouldn't consume past the end");
+		assert!(cursor.fill_buf().unwrap().is_empty());
+	}
+}
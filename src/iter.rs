@@ -0,0 +1,150 @@
+use core::iter::FusedIterator;
+
+#[cfg(doc)]
+use crate::CollectionCursor;
+use crate::{IndexableCollection, IndexableCollectionMut};
+
+/// An iterator over a range of items within a cursor's underlying collection.
+///
+/// This struct is created by [`CollectionCursor::iter_remaining()`] and
+/// [`CollectionCursor::iter_consumed()`].
+#[derive(Debug)]
+pub struct Iter<'a, Tape: IndexableCollection> {
+	/// The collection being iterated over.
+	collection: &'a Tape,
+	/// The index of the next item to be yielded from the front of the iterator.
+	front: usize,
+	/// One index past the next item to be yielded from the back of the iterator.
+	back: usize,
+}
+
+impl<'a, Tape: IndexableCollection> Iter<'a, Tape> {
+	/// Creates an iterator over the items in `front..back`. `back` is clamped to the length of
+	/// `collection`.
+	pub(crate) fn new(collection: &'a Tape, front: usize, back: usize) -> Self {
+		let back = back.min(collection.len());
+		Self {
+			collection,
+			front: front.min(back),
+			back,
+		}
+	}
+}
+
+impl<Tape: IndexableCollection> Clone for Iter<'_, Tape> {
+	fn clone(&self) -> Self {
+		Self { ..*self }
+	}
+}
+
+impl<'a, Tape: IndexableCollection> Iterator for Iter<'a, Tape> {
+	type Item = &'a Tape::Item;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front >= self.back {
+			return None;
+		}
+
+		let item = self.collection.get_item(self.front)?;
+		self.front += 1;
+		Some(item)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<Tape: IndexableCollection> DoubleEndedIterator for Iter<'_, Tape> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front >= self.back {
+			return None;
+		}
+
+		let item = self.collection.get_item(self.back - 1)?;
+		self.back -= 1;
+		Some(item)
+	}
+}
+
+impl<Tape: IndexableCollection> ExactSizeIterator for Iter<'_, Tape> {
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+impl<Tape: IndexableCollection> FusedIterator for Iter<'_, Tape> {}
+
+/// A mutable iterator over a range of items within a cursor's underlying collection.
+///
+/// Unlike [`Iter`], this does not implement [`Iterator`]. [`IndexableCollectionMut`] only allows
+/// one item to be borrowed mutably at a time, so each item must be released before the next one is
+/// requested. Items can instead be visited with a `while let Some(item) = iter.next()` loop, or
+/// with [`IterMut::for_each()`].
+///
+/// This struct is created by [`CollectionCursor::iter_remaining_mut()`].
+#[derive(Debug)]
+pub struct IterMut<'a, Tape: IndexableCollectionMut> {
+	/// The collection being iterated over.
+	collection: &'a mut Tape,
+	/// The index of the next item to be yielded from the front of the iterator.
+	front: usize,
+	/// One index past the next item to be yielded from the back of the iterator.
+	back: usize,
+}
+
+impl<'a, Tape: IndexableCollectionMut> IterMut<'a, Tape> {
+	/// Creates an iterator over the items in `front..back`. `back` is clamped to the length of
+	/// `collection`.
+	pub(crate) fn new(collection: &'a mut Tape, front: usize, back: usize) -> Self {
+		let back = back.min(collection.len());
+		Self {
+			collection,
+			front: front.min(back),
+			back,
+		}
+	}
+
+	/// Returns a mutable reference to the next item, or `None` if no items remain.
+	#[allow(
+		clippy::should_implement_trait,
+		reason = "This is a lending iterator, so it cannot implement `Iterator`"
+	)]
+	pub fn next(&mut self) -> Option<&mut Tape::Item> {
+		if self.front >= self.back {
+			return None;
+		}
+
+		let index = self.front;
+		self.front += 1;
+		self.collection.get_item_mut(index)
+	}
+
+	/// Returns a mutable reference to the last remaining item, or `None` if no items remain.
+	pub fn next_back(&mut self) -> Option<&mut Tape::Item> {
+		if self.front >= self.back {
+			return None;
+		}
+
+		self.back -= 1;
+		self.collection.get_item_mut(self.back)
+	}
+
+	/// Calls `f` with a mutable reference to each remaining item, in order.
+	pub fn for_each<F: FnMut(&mut Tape::Item)>(mut self, mut f: F) {
+		while let Some(item) = self.next() {
+			f(item);
+		}
+	}
+
+	/// Returns the number of items remaining.
+	pub fn len(&self) -> usize {
+		self.back - self.front
+	}
+
+	/// Returns whether no items remain.
+	pub fn is_empty(&self) -> bool {
+		self.front >= self.back
+	}
+}
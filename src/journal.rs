@@ -0,0 +1,379 @@
+extern crate alloc;
+
+use alloc::{collections::VecDeque, vec::Vec};
+use core::ops::Deref;
+
+use crate::{
+	CollectionCursor, IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable,
+	PatchOp,
+};
+
+/// Edits which undo or redo an earlier edit made through a [`JournaledCursor`].
+///
+/// Applying an entry produces the entry which reverses it, so the same type is used for both the
+/// undo and redo histories.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct JournalEntry<Item> {
+	/// The position the cursor was at when the edit was made, which it is moved back to once the
+	/// entry is applied.
+	pos: usize,
+	/// The edits to apply, in order.
+	ops: Vec<PatchOp<Item>>,
+}
+
+/// Applies a [`PatchOp`] which inserts or removes items. See
+/// [`CollectionCursor::apply_patch_op()`].
+type StructuralOpFn<Tape> = fn(
+	&mut CollectionCursor<Tape>,
+	PatchOp<<Tape as IndexableCollection>::Item>,
+	Option<&mut Vec<PatchOp<<Tape as IndexableCollection>::Item>>>,
+);
+
+/// Wraps a [`CollectionCursor`], recording every edit made through it so that the edits can be
+/// undone and redone.
+///
+/// Undoing or redoing an edit also moves the cursor back to where it was when the edit was made.
+/// Making a new edit discards every edit which could have been redone.
+///
+/// The wrapped cursor can be read through [`Deref`], but can only be moved or edited through the
+/// methods on this type, so that no edit is missed. Moving the cursor is not recorded.
+#[derive(Clone, Debug)]
+pub struct JournaledCursor<Tape: IndexableCollection> {
+	/// The cursor whose edits are being recorded.
+	cursor: CollectionCursor<Tape>,
+	/// The entries which reverse the most recent edits, with the most recent at the back.
+	undo_history: VecDeque<JournalEntry<Tape::Item>>,
+	/// The entries which reapply the most recently undone edits, with the most recent at the back.
+	redo_history: Vec<JournalEntry<Tape::Item>>,
+	/// The maximum number of edits which can be undone.
+	history_depth: usize,
+	/// Applies the insertions and removals within the histories. This is only set once such an
+	/// edit is made, as doing so requires an [`IndexableCollectionResizable`] collection.
+	apply_structural_op: Option<StructuralOpFn<Tape>>,
+}
+
+impl<Tape: IndexableCollection> JournaledCursor<Tape> {
+	/// Wraps `cursor`, recording every later edit. There is no limit to the number of edits which
+	/// can be undone.
+	pub fn new(cursor: CollectionCursor<Tape>) -> Self {
+		Self::with_history_depth(cursor, usize::MAX)
+	}
+
+	/// Wraps `cursor`, recording every later edit. Only the most recent `history_depth` edits can
+	/// be undone; older edits are forgotten.
+	pub fn with_history_depth(cursor: CollectionCursor<Tape>, history_depth: usize) -> Self {
+		Self {
+			cursor,
+			undo_history: VecDeque::new(),
+			redo_history: Vec::new(),
+			history_depth,
+			apply_structural_op: None,
+		}
+	}
+
+	/// Returns the maximum number of edits which can be undone.
+	pub fn history_depth(&self) -> usize {
+		self.history_depth
+	}
+
+	/// Sets the maximum number of edits which can be undone. If more edits than this can currently
+	/// be undone, the oldest are forgotten.
+	pub fn set_history_depth(&mut self, history_depth: usize) {
+		self.history_depth = history_depth;
+		self.trim_undo_history();
+	}
+
+	/// Returns the number of edits which can currently be undone.
+	pub fn undo_count(&self) -> usize {
+		self.undo_history.len()
+	}
+
+	/// Returns the number of edits which can currently be redone.
+	pub fn redo_count(&self) -> usize {
+		self.redo_history.len()
+	}
+
+	/// Forgets every recorded edit, so that nothing can be undone or redone. The cursor and the
+	/// inner collection are left untouched.
+	pub fn clear_history(&mut self) {
+		self.undo_history.clear();
+		self.redo_history.clear();
+	}
+
+	/// Unwraps this `JournaledCursor`, returning the cursor. The recorded edits are discarded.
+	pub fn into_inner(self) -> CollectionCursor<Tape> {
+		self.cursor
+	}
+
+	/// Records `undo_ops` as the edits which reverse a newly made edit, discarding the redo
+	/// history. `pos` is the position the cursor was at when the edit was made.
+	fn record(&mut self, pos: usize, undo_ops: Vec<PatchOp<Tape::Item>>) {
+		if undo_ops.is_empty() {
+			return;
+		}
+		self.redo_history.clear();
+		self.undo_history
+			.push_back(JournalEntry { pos, ops: undo_ops });
+		self.trim_undo_history();
+	}
+
+	/// Forgets the oldest recorded edits, until no more than `self.history_depth` remain.
+	fn trim_undo_history(&mut self) {
+		while self.undo_history.len() > self.history_depth {
+			self.undo_history.pop_front();
+		}
+	}
+
+	/// Runs `f` with the wrapped cursor. Used to move the cursor, which is not recorded.
+	fn move_cursor<R, F: FnOnce(&mut CollectionCursor<Tape>) -> R>(&mut self, f: F) -> R {
+		f(&mut self.cursor)
+	}
+}
+
+impl<Tape: IndexableCollection> Deref for JournaledCursor<Tape> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection> JournaledCursor<Tape> {
+	forward_cursor_movement!(move_cursor);
+}
+
+impl<Tape: IndexableCollectionMut> JournaledCursor<Tape> {
+	/// Sets the slot at the cursor to `item`, recording the edit.
+	///
+	/// # Panics
+	/// Panics if `self.position() >= self.get_ref().len()`.
+	pub fn set_item_at_cursor(&mut self, item: Tape::Item) {
+		let pos = self.cursor.pos;
+		let old_item = self
+			.cursor
+			.apply_set_op(pos, item)
+			.expect("cursor position should point at an item");
+		self.record(
+			pos,
+			Vec::from([PatchOp::Set {
+				index: pos,
+				item: old_item,
+			}]),
+		);
+	}
+
+	/// Undoes the most recent edit which hasn't been undone yet, moving the cursor back to where it
+	/// was when that edit was made.
+	///
+	/// Returns `true` if an edit was undone, or `false` if there was nothing to undo.
+	pub fn undo(&mut self) -> bool {
+		let Some(entry) = self.undo_history.pop_back() else {
+			return false;
+		};
+		let redo = self.apply(entry);
+		self.redo_history.push(redo);
+		true
+	}
+
+	/// Redoes the most recently undone edit, moving the cursor back to where it was when that edit
+	/// was made.
+	///
+	/// Returns `true` if an edit was redone, or `false` if there was nothing to redo.
+	pub fn redo(&mut self) -> bool {
+		let Some(entry) = self.redo_history.pop() else {
+			return false;
+		};
+		let undo = self.apply(entry);
+		self.undo_history.push_back(undo);
+		self.trim_undo_history();
+		true
+	}
+
+	/// Applies the edits within `entry` through the cursor, then moves the cursor to `entry.pos`.
+	/// Returns the entry which reverses it.
+	fn apply(&mut self, entry: JournalEntry<Tape::Item>) -> JournalEntry<Tape::Item> {
+		let mut inverse = Vec::with_capacity(entry.ops.len());
+		for op in entry.ops {
+			match op {
+				PatchOp::Set { index, item } => {
+					if let Some(old_item) = self.cursor.apply_set_op(index, item) {
+						inverse.push(PatchOp::Set {
+							index,
+							item: old_item,
+						});
+					}
+				}
+				op => {
+					if let Some(apply_structural_op) = self.apply_structural_op {
+						apply_structural_op(&mut self.cursor, op, Some(&mut inverse));
+					}
+				}
+			}
+		}
+		inverse.reverse();
+		self.cursor.pos = entry.pos.min(self.cursor.inner.len());
+		JournalEntry {
+			pos: entry.pos,
+			ops: inverse,
+		}
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> JournaledCursor<Tape> {
+	/// Inserts `item` at the cursor, as described by
+	/// [`CollectionCursor::insert_item_at_cursor()`], recording the edit.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. See [`CollectionCursor::insert_item_at_cursor()`].
+	pub fn insert_item_at_cursor(&mut self, item: Tape::Item) {
+		let pos = self.cursor.pos;
+		self.cursor.insert_item_at_cursor(item);
+		self.record_structural(pos, Vec::from([PatchOp::Remove { index: pos }]));
+	}
+
+	/// Removes every item from the collection and moves the cursor to index `0`, recording the
+	/// edit.
+	pub fn clear(&mut self) {
+		let pos = self.cursor.pos;
+		let mut undo_ops = Vec::with_capacity(self.cursor.inner.len());
+		self.cursor
+			.apply_patch_op(PatchOp::Clear, Some(&mut undo_ops));
+		undo_ops.reverse();
+		self.record_structural(pos, undo_ops);
+	}
+
+	/// Records `undo_ops`, which may insert or remove items, as described by [`Self::record()`].
+	fn record_structural(&mut self, pos: usize, undo_ops: Vec<PatchOp<Tape::Item>>) {
+		self.apply_structural_op = Some(CollectionCursor::apply_patch_op);
+		self.record(pos, undo_ops);
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> JournaledCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Removes and returns the item at the cursor, as described by
+	/// [`CollectionCursor::remove_item_at_cursor()`]. The edit is only recorded if an item was
+	/// removed.
+	///
+	/// As the removed item is kept so that the removal can be undone, a clone of it is returned.
+	pub fn remove_item_at_cursor(&mut self) -> Option<Tape::Item> {
+		let pos = self.cursor.pos;
+		let item = self.cursor.remove_item_at_cursor()?;
+		self.record_structural(
+			pos,
+			Vec::from([PatchOp::Insert {
+				index: pos,
+				item: item.clone(),
+			}]),
+		);
+		Some(item)
+	}
+}
+
+#[cfg(test)]
+mod journal_tests {
+	use alloc::vec::Vec;
+
+	use super::*;
+
+	#[test]
+	fn undo_and_redo() {
+		let mut cursor = JournaledCursor::new(CollectionCursor::new(Vec::from([0, 1, 2])));
+
+		cursor.seek_relative(1).unwrap();
+		cursor.set_item_at_cursor(10);
+		cursor.seek_to_end();
+		cursor.insert_item_at_cursor(3);
+		cursor.seek_to_start();
+		assert_eq!(cursor.remove_item_at_cursor(), Some(0));
+		cursor.clear();
+		assert_eq!(*cursor.get_ref(), []);
+		assert_eq!(cursor.undo_count(), 4);
+
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [10, 2, 3], "should undo the clear");
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [0, 10, 2, 3], "should undo the removal");
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [0, 10, 2], "should undo the insertion");
+		assert_eq!(
+			cursor.position(),
+			3,
+			"should move back to where the edit was made"
+		);
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [0, 1, 2], "should undo the set");
+		assert_eq!(cursor.position(), 1);
+		assert!(!cursor.undo(), "shouldn't undo past the first edit");
+
+		assert!(cursor.redo());
+		assert!(cursor.redo());
+		assert_eq!(*cursor.get_ref(), [0, 10, 2, 3]);
+		assert_eq!(cursor.redo_count(), 2);
+
+		cursor.seek_to_start();
+		cursor.set_item_at_cursor(5);
+		assert_eq!(cursor.redo_count(), 0, "should discard the redo history");
+		assert!(!cursor.redo());
+		assert_eq!(*cursor.get_ref(), [5, 10, 2, 3]);
+	}
+
+	#[test]
+	fn history_depth() {
+		let mut cursor = JournaledCursor::with_history_depth(CollectionCursor::new(Vec::new()), 2);
+
+		for item in 0..4 {
+			cursor.insert_item_at_cursor(item);
+		}
+		assert_eq!(cursor.undo_count(), 2, "should forget the oldest edits");
+		assert!(cursor.undo());
+		assert!(cursor.undo());
+		assert!(!cursor.undo());
+		assert_eq!(*cursor.get_ref(), [1, 0]);
+
+		cursor.redo();
+		cursor.set_history_depth(0);
+		assert_eq!(cursor.undo_count(), 0);
+		cursor.insert_item_at_cursor(4);
+		assert!(!cursor.undo(), "shouldn't record edits with a depth of `0`");
+	}
+
+	#[test]
+	fn redo_clear() {
+		let mut cursor = JournaledCursor::new(CollectionCursor::new(Vec::from([0, 1, 2])));
+
+		assert_eq!(cursor.next_item(), Some(&0));
+		assert_eq!(cursor.seek_to_match(|&item| item == 2), Some(2));
+		cursor.clear();
+		cursor.clear();
+		assert_eq!(cursor.undo_count(), 1, "shouldn't record clearing nothing");
+
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [0, 1, 2]);
+		assert_eq!(cursor.position(), 2);
+		assert!(cursor.redo());
+		assert_eq!(*cursor.get_ref(), []);
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [0, 1, 2], "should undo a redone clear");
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn fixed_size_collection() {
+		let mut cursor = JournaledCursor::new(CollectionCursor::new([0, 1, 2]));
+
+		cursor.set_item_at_cursor(10);
+		cursor.seek_to_last_item();
+		cursor.set_item_at_cursor(12);
+		assert_eq!(cursor.prev_item(), Some(&1));
+		assert!(cursor.undo());
+		assert_eq!(*cursor.get_ref(), [10, 1, 2]);
+		assert_eq!(cursor.position(), 2);
+		assert!(cursor.undo());
+		assert!(cursor.redo());
+		assert_eq!(*cursor.get_ref(), [10, 1, 2]);
+		assert_eq!(cursor.position(), 0);
+	}
+}
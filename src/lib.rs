@@ -1,7 +1,241 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{cmp::Ordering, fmt, iter::Skip, num::NonZeroUsize, ops::Range};
+
+/// Implements the conversions between [`SeekFrom`] and `$module::SeekFrom`, which has the same
+/// variants, but always uses 64-bit offsets. Used by the `io` and `embedded_io` modules.
+#[cfg(any(feature = "std", feature = "embedded-io"))]
+macro_rules! impl_seek_from_conversions {
+	($module:ident, $name:literal) => {
+		impl TryFrom<$module::SeekFrom> for SeekFrom {
+			type Error = core::num::TryFromIntError;
+
+			#[doc = concat!("Converts from ", $name, ".")]
+			///
+			/// # Errors
+			/// Returns an error if the offset doesn't fit within a `usize` (for `SeekFrom::Start`)
+			/// or an `isize` (otherwise).
+			fn try_from(value: $module::SeekFrom) -> Result<Self, Self::Error> {
+				Ok(match value {
+					$module::SeekFrom::Start(p) => Self::Start(usize::try_from(p)?),
+					$module::SeekFrom::End(p) => Self::End(isize::try_from(p)?),
+					$module::SeekFrom::Current(p) => Self::Current(isize::try_from(p)?),
+				})
+			}
+		}
+
+		impl From<SeekFrom> for $module::SeekFrom {
+			#[doc = concat!("Converts to ", $name, ".")]
+			fn from(value: SeekFrom) -> Self {
+				// `usize` and `isize` are at most 64 bits wide on every platform Rust supports, so
+				// these casts are lossless.
+				match value {
+					SeekFrom::Start(p) => Self::Start(p as u64),
+					SeekFrom::End(p) => Self::End(p as i64),
+					SeekFrom::Current(p) => Self::Current(p as i64),
+				}
+			}
+		}
+	};
+}
+
+/// Implements the methods which move the cursor on a type wrapping a [`CollectionCursor`], such
+/// as [`JournaledCursor`]. Each method runs the matching method of the wrapped cursor through
+/// `self.$forward()`, which should take a closure over `&mut CollectionCursor<Tape>`, so that the
+/// wrapper can react to the movement.
+///
+/// This must be invoked within an `impl` block where `Tape: IndexableCollection`, and where the
+/// wrapper implements `Deref<Target = CollectionCursor<Tape>>`.
+#[cfg(feature = "alloc")]
+macro_rules! forward_cursor_movement {
+	($forward:ident) => {
+		/// Moves the cursor as described by [`CollectionCursor::seek()`].
+		///
+		/// # Errors
+		/// Returns the error returned by [`CollectionCursor::seek()`], if any.
+		pub fn seek(&mut self, pos: $crate::SeekFrom) -> Result<usize, $crate::OutOfBoundsError> {
+			self.$forward(|cursor| cursor.seek(pos))
+		}
+
+		/// Moves the cursor as described by [`CollectionCursor::seek_relative()`].
+		///
+		/// # Errors
+		/// Returns the error returned by [`CollectionCursor::seek_relative()`], if any.
+		pub fn seek_relative(&mut self, offset: isize) -> Result<usize, $crate::OutOfBoundsError> {
+			self.$forward(|cursor| cursor.seek_relative(offset))
+		}
+
+		/// Moves the cursor to the beginning of the collection.
+		pub fn seek_to_start(&mut self) {
+			self.$forward(CollectionCursor::seek_to_start);
+		}
+
+		/// Moves the cursor to one index past the last item, as described by
+		/// [`CollectionCursor::seek_to_end()`].
+		pub fn seek_to_end(&mut self) {
+			self.$forward(CollectionCursor::seek_to_end);
+		}
+
+		/// Moves the cursor to the last item, as described by
+		/// [`CollectionCursor::seek_to_last_item()`].
+		pub fn seek_to_last_item(&mut self) {
+			self.$forward(CollectionCursor::seek_to_last_item);
+		}
+
+		/// Moves the cursor forwards by one index, as described by
+		/// [`CollectionCursor::seek_forward_one()`].
+		pub fn seek_forward_one(&mut self) -> bool {
+			self.$forward(CollectionCursor::seek_forward_one)
+		}
+
+		/// Moves the cursor backwards by one index, as described by
+		/// [`CollectionCursor::seek_backward_one()`].
+		pub fn seek_backward_one(&mut self) -> bool {
+			self.$forward(CollectionCursor::seek_backward_one)
+		}
+
+		/// Moves the cursor forwards by up to `n` items, as described by
+		/// [`CollectionCursor::advance_by()`].
+		///
+		/// # Errors
+		/// Returns the error returned by [`CollectionCursor::advance_by()`], if any.
+		pub fn advance_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+			self.$forward(|cursor| cursor.advance_by(n))
+		}
+
+		/// Moves the cursor backwards by up to `n` items, as described by
+		/// [`CollectionCursor::retreat_by()`].
+		///
+		/// # Errors
+		/// Returns the error returned by [`CollectionCursor::retreat_by()`], if any.
+		pub fn retreat_by(&mut self, n: usize) -> Result<(), core::num::NonZeroUsize> {
+			self.$forward(|cursor| cursor.retreat_by(n))
+		}
+
+		/// Returns the item at the cursor, then moves the cursor forwards, as described by
+		/// [`CollectionCursor::next_item()`].
+		pub fn next_item(&mut self) -> Option<&Tape::Item> {
+			let moved = self.$forward(|cursor| cursor.next_item().is_some());
+			moved
+				.then(|| self.get_ref_at(self.position() - 1))
+				.flatten()
+		}
+
+		/// Moves the cursor backwards, then returns the item at the cursor, as described by
+		/// [`CollectionCursor::prev_item()`].
+		pub fn prev_item(&mut self) -> Option<&Tape::Item> {
+			let moved = self.$forward(|cursor| cursor.prev_item().is_some());
+			moved.then(|| self.get_ref_at(self.position())).flatten()
+		}
+
+		/// Moves the cursor forwards past every consecutive item matching `pred`, as described by
+		/// [`CollectionCursor::skip_forward_while()`].
+		pub fn skip_forward_while<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> usize {
+			self.$forward(|cursor| cursor.skip_forward_while(pred))
+		}
+
+		/// Moves the cursor backwards past every consecutive item matching `pred`, as described by
+		/// [`CollectionCursor::skip_backward_while()`].
+		pub fn skip_backward_while<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> usize {
+			self.$forward(|cursor| cursor.skip_backward_while(pred))
+		}
+
+		/// Moves the cursor to the first item from `origin` matching `pred`, as described by
+		/// [`CollectionCursor::find()`].
+		pub fn find<F: FnMut(&Tape::Item) -> bool>(
+			&mut self,
+			origin: $crate::SearchOrigin,
+			pred: F,
+		) -> Option<usize> {
+			self.$forward(|cursor| cursor.find(origin, pred))
+		}
+
+		/// Moves the cursor to the first item from the cursor matching `pred`, as described by
+		/// [`CollectionCursor::seek_to_match()`].
+		pub fn seek_to_match<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> Option<usize> {
+			self.$forward(|cursor| cursor.seek_to_match(pred))
+		}
+
+		/// Moves the cursor to the next item equal to `value`, as described by
+		/// [`CollectionCursor::seek_to_next()`].
+		pub fn seek_to_next(&mut self, value: &Tape::Item) -> Option<usize>
+		where
+			Tape::Item: PartialEq,
+		{
+			self.$forward(|cursor| cursor.seek_to_next(value))
+		}
+
+		/// Moves the cursor to the previous item equal to `value`, as described by
+		/// [`CollectionCursor::seek_to_prev()`].
+		pub fn seek_to_prev(&mut self, value: &Tape::Item) -> Option<usize>
+		where
+			Tape::Item: PartialEq,
+		{
+			self.$forward(|cursor| cursor.seek_to_prev(value))
+		}
+	};
+}
+
+mod byte_ops;
+#[cfg(feature = "alloc")]
+mod checkpoint_stack;
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+mod error;
+#[cfg(feature = "debug-invariants")]
+mod generation;
+mod guard;
+#[cfg(any(feature = "alloc", feature = "debug-invariants", feature = "metrics"))]
+mod ignored;
+mod index_adapter;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+#[cfg(feature = "alloc")]
+mod journal;
+#[cfg(feature = "alloc")]
+mod marks;
+#[cfg(feature = "memchr")]
+mod memchr;
+mod metrics;
+#[cfg(feature = "alloc")]
+mod multi_cursor;
+#[cfg(feature = "alloc")]
+mod observer;
+#[cfg(feature = "alloc")]
+mod patch;
+#[cfg(feature = "alloc")]
+mod register;
+mod span;
 mod trait_impls_by_crate;
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+pub use error::{
+	InvariantError, NotEnoughItems, OutOfBoundsError, PatchError, UnexpectedItem, Utf8CursorError,
+	VarintError,
+};
+pub use guard::{ClampGuard, PositionGuard};
+pub use index_adapter::IndexAdapter;
+pub use iter::{Iter, IterMut};
+#[cfg(feature = "alloc")]
+pub use journal::JournaledCursor;
+#[cfg(feature = "metrics")]
+pub use metrics::CursorMetrics;
+#[cfg(feature = "alloc")]
+pub use multi_cursor::MultiCursor;
+#[cfg(feature = "alloc")]
+pub use observer::{CursorEvent, CursorEventKind, CursorObserver, ObservedCursor};
+#[cfg(feature = "alloc")]
+pub use patch::{Patch, PatchOp, RecordingCursor};
+#[cfg(feature = "alloc")]
+pub use register::Register;
+pub use span::CursorSpan;
+
 #[derive(Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectionCursor<Tape> {
@@ -16,19 +250,94 @@ pub struct CollectionCursor<Tape> {
 	/// the pos back within the collection's bounds. However, such a thing is a logic error, and is
 	/// on the user of the struct to avoid.
 	pos: usize,
+	/// A single saved position, which the cursor can be returned to later. See
+	/// [`Self::save_checkpoint()`].
+	///
+	/// Unlike `pos`, this is not kept within the bounds of the collection; it is clamped when it is
+	/// restored instead.
+	#[cfg_attr(feature = "serde", serde(default))]
+	checkpoint: Option<usize>,
+	/// A stack of saved positions, for nested speculative regions. See
+	/// [`Self::push_checkpoint()`].
+	///
+	/// Like `checkpoint`, these are clamped when they are restored.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(feature = "serde", serde(default))]
+	checkpoint_stack: alloc::vec::Vec<usize>,
+	/// Named positions, which the cursor can be returned to later. See [`Self::set_mark()`].
+	///
+	/// Like `checkpoint`, these are clamped when they are restored.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(feature = "serde", serde(default))]
+	marks: alloc::collections::BTreeMap<alloc::string::String, usize>,
+	/// How seeks which would move the cursor out of the collection's bounds are handled.
+	#[cfg_attr(feature = "serde", serde(default))]
+	seek_policy: SeekPolicy,
+	/// Counts structural edits to the collection, so that stale saved positions and spans can be
+	/// detected.
+	#[cfg(feature = "debug-invariants")]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	generation: ignored::Ignored<generation::Generation>,
+	/// Counts of the operations performed through the cursor. See [`Self::metrics()`].
+	#[cfg(feature = "metrics")]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	metrics: ignored::Ignored<metrics::CursorMetrics>,
+	/// The structural edits made while an [`ObservedCursor`] is running a method on this cursor,
+	/// which it will report to its observer afterwards. `None` at any other time.
+	#[cfg(feature = "alloc")]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	observed_edits: ignored::Ignored<Option<alloc::vec::Vec<observer::CursorEventKind>>>,
 }
 
 impl<Tape> CollectionCursor<Tape> {
 	/// Creates a new `CollectionCursor` wrapping the provided collection.
 	///
 	/// The cursor's initial position will always be `0`.
-	pub fn new(inner: Tape) -> Self {
+	///
+	/// As this is a `const fn`, it can be used to create a cursor within a `const` or `static`.
+	pub const fn new(inner: Tape) -> Self {
+		Self::with_seek_policy(inner, SeekPolicy::Strict)
+	}
+
+	/// Creates a new `CollectionCursor` wrapping the provided collection, which handles
+	/// out-of-bounds seeks according to `seek_policy`.
+	///
+	/// The cursor's initial position will always be `0`.
+	pub const fn with_seek_policy(inner: Tape, seek_policy: SeekPolicy) -> Self {
 		Self {
 			inner,
-			pos: Default::default(),
+			pos: 0,
+			checkpoint: None,
+			#[cfg(feature = "alloc")]
+			checkpoint_stack: alloc::vec::Vec::new(),
+			#[cfg(feature = "alloc")]
+			marks: alloc::collections::BTreeMap::new(),
+			seek_policy,
+			#[cfg(feature = "debug-invariants")]
+			generation: ignored::Ignored(generation::Generation::new()),
+			#[cfg(feature = "metrics")]
+			metrics: ignored::Ignored(metrics::CursorMetrics {
+				seeks: 0,
+				reads: 0,
+				writes: 0,
+				inserts: 0,
+				removals: 0,
+			}),
+			#[cfg(feature = "alloc")]
+			observed_edits: ignored::Ignored(None),
 		}
 	}
 
+	/// Returns how this cursor handles seeks which would move it out of the collection's bounds.
+	pub fn seek_policy(&self) -> SeekPolicy {
+		self.seek_policy
+	}
+
+	/// Changes how this cursor handles seeks which would move it out of the collection's bounds.
+	pub fn set_seek_policy(&mut self, seek_policy: SeekPolicy) {
+		self.seek_policy = seek_policy;
+	}
+
 	/// Returns the current position of the cursor.
 	///
 	/// This can be assumed to uphold `0 <= cursor_position <= self.get_ref().len()`, where
@@ -48,47 +357,537 @@ impl<Tape> CollectionCursor<Tape> {
 	/// If the underlying collection's length is modified, you must ensure that
 	/// `0 <= self.position() <= self.get_ref().len()` is upheld before the next attempt to
 	/// read/write at the cursor. [`Self::clamp_to_last_item()`] and [`Self::clamp_to_end()`] may be
-	/// useful in these cases.
+	/// useful in these cases, or [`Self::edit()`] or [`Self::get_mut_guarded()`] can be used in
+	/// place of this method to clamp the cursor automatically.
 	///
 	/// Failure to do so is a logic error. The behavior resulting from such a logic error is not
 	/// specified, but will generally result in panics, incorrect results, and other such unwanted
 	/// behavior.
+	///
+	/// With the `debug-invariants` feature, the cursor assumes the collection is structurally
+	/// edited through the returned reference. Restoring a checkpoint or mark saved beforehand which
+	/// is still within the bounds of the collection, or using a span created beforehand, will then
+	/// panic.
 	pub fn get_mut(&mut self) -> &mut Tape {
+		#[cfg(feature = "debug-invariants")]
+		self.generation.record_untracked_edit();
+
 		&mut self.inner
 	}
 
 	pub fn into_inner(self) -> Tape {
 		self.inner
 	}
+
+	/// Deconstructs the cursor into the underlying collection and the cursor's position.
+	///
+	/// The cursor can be rebuilt from these parts using [`Self::from_parts()`].
+	pub fn into_parts(self) -> (Tape, usize) {
+		(self.inner, self.pos)
+	}
+
+	/// Exchanges the position of this cursor with the position of `other`. The underlying
+	/// collections are left untouched.
+	///
+	/// To swap entire cursors (including their collections), use [`core::mem::swap`] instead.
+	///
+	/// # Warning
+	/// If the two collections differ in length, you must ensure that each position is valid for the
+	/// collection it is now paired with, in the same way as described for [`Self::get_mut()`].
+	pub fn swap_positions_with(&mut self, other: &mut Self) {
+		core::mem::swap(&mut self.pos, &mut other.pos);
+	}
+
+	/// Returns the saved checkpoint, or `None` if no checkpoint is saved.
+	pub fn checkpoint(&self) -> Option<usize> {
+		self.checkpoint
+	}
+
+	/// Saves the current position as the checkpoint, replacing any previously-saved checkpoint.
+	///
+	/// Only a single checkpoint is kept. The cursor can be returned to it using
+	/// [`Self::restore_checkpoint()`].
+	///
+	/// When items are inserted or removed through the cursor's own methods, the checkpoint is
+	/// shifted so that it keeps pointing at the same item. Changes made through
+	/// [`Self::get_mut()`] are not tracked.
+	pub fn save_checkpoint(&mut self) {
+		#[cfg(feature = "debug-invariants")]
+		self.before_saving_position();
+
+		self.checkpoint = Some(self.pos);
+	}
+
+	/// Removes the saved checkpoint, if there is one.
+	pub fn clear_checkpoint(&mut self) {
+		self.checkpoint = None;
+	}
+
+	/// Applies `f` to every saved position: the checkpoint, the checkpoint stack, and the marks.
+	///
+	/// This is called after every structural edit made through the cursor.
+	pub(crate) fn adjust_saved_positions<F: Fn(usize) -> usize>(&mut self, f: F) {
+		#[cfg(feature = "debug-invariants")]
+		self.generation.record_tracked_edit();
+
+		if let Some(checkpoint) = &mut self.checkpoint {
+			*checkpoint = f(*checkpoint);
+		}
+
+		#[cfg(feature = "alloc")]
+		for saved in self
+			.checkpoint_stack
+			.iter_mut()
+			.chain(self.marks.values_mut())
+		{
+			*saved = f(*saved);
+		}
+	}
+}
+
+impl<Tape: Default> CollectionCursor<Tape> {
+	/// Takes the cursor out of `self`, leaving behind a cursor wrapping a default (usually empty)
+	/// collection, with its position at `0`.
+	pub fn take(&mut self) -> Self {
+		core::mem::take(self)
+	}
+}
+
+impl<Tape> AsRef<Tape> for CollectionCursor<Tape> {
+	/// Gets a reference to the underlying collection. This is equivalent to
+	/// [`CollectionCursor::get_ref()`].
+	fn as_ref(&self) -> &Tape {
+		&self.inner
+	}
+}
+
+impl<Tape> AsMut<Tape> for CollectionCursor<Tape> {
+	/// Gets a mutable reference to the underlying collection. This is equivalent to
+	/// [`CollectionCursor::get_mut()`].
+	///
+	/// # Warning
+	/// The same warning as [`CollectionCursor::get_mut()`] applies here: if the underlying
+	/// collection's length is modified, you must ensure that
+	/// `0 <= self.position() <= self.get_ref().len()` is upheld before the next attempt to
+	/// read/write at the cursor.
+	fn as_mut(&mut self) -> &mut Tape {
+		self.get_mut()
+	}
+}
+
+impl<Tape: IntoIterator> IntoIterator for CollectionCursor<Tape> {
+	type Item = Tape::Item;
+	type IntoIter = Skip<Tape::IntoIter>;
+
+	/// Consumes the cursor, returning an iterator over the items from the cursor's position
+	/// onwards.
+	fn into_iter(self) -> Self::IntoIter {
+		self.inner.into_iter().skip(self.pos)
+	}
+}
+
+impl<'a, Tape: IndexableCollection> IntoIterator for &'a CollectionCursor<Tape> {
+	type Item = &'a Tape::Item;
+	type IntoIter = Iter<'a, Tape>;
+
+	/// Returns an iterator over the items from the cursor's position onwards. This is equivalent
+	/// to [`CollectionCursor::iter_remaining()`].
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter_remaining()
+	}
+}
+
+impl<'a, Tape> IntoIterator for &'a mut CollectionCursor<Tape>
+where
+	&'a mut Tape: IntoIterator,
+{
+	type Item = <&'a mut Tape as IntoIterator>::Item;
+	type IntoIter = Skip<<&'a mut Tape as IntoIterator>::IntoIter>;
+
+	/// Returns an iterator over mutable references to the items from the cursor's position
+	/// onwards.
+	fn into_iter(self) -> Self::IntoIter {
+		let pos = self.pos;
+		(&mut self.inner).into_iter().skip(pos)
+	}
 }
 
 impl<Tape: IndexableCollection> CollectionCursor<Tape> {
+	/// Creates a new `CollectionCursor` wrapping the provided collection, with the cursor placed at
+	/// `pos`.
+	///
+	/// # Errors
+	/// If `pos > inner.len()`, the provided parts are returned back to the caller.
+	pub fn from_parts(inner: Tape, pos: usize) -> Result<Self, (Tape, usize)> {
+		if pos <= inner.len() {
+			Ok(Self {
+				pos,
+				..Self::new(inner)
+			})
+		} else {
+			Err((inner, pos))
+		}
+	}
+
 	/// Returns whether the cursor is at the end of the collection (one index past the last item in
 	/// the collection).
 	pub fn is_cursor_at_end(&self) -> bool {
 		self.pos == self.inner.len()
 	}
 
+	/// Returns the position `n` indices after the cursor, clamped to one index past the last item.
+	///
+	/// The cursor is not moved.
+	pub fn position_saturating_add(&self, n: usize) -> usize {
+		self.pos.saturating_add(n).min(self.inner.len())
+	}
+
+	/// Returns the position `n` indices before the cursor, clamped to the beginning of the
+	/// collection.
+	///
+	/// The cursor is not moved.
+	pub fn position_saturating_sub(&self, n: usize) -> usize {
+		self.pos.saturating_sub(n)
+	}
+
+	/// Returns whether `n` is a position the cursor is allowed to be at - that is, whether
+	/// `0 <= n <= self.get_ref().len()`.
+	pub fn is_valid_position(&self, n: usize) -> bool {
+		n <= self.inner.len()
+	}
+
+	/// Checks that `0 <= self.position() <= self.get_ref().len()` is upheld.
+	///
+	/// This is mainly useful for validating the cursor after modifying the underlying collection
+	/// through [`Self::get_mut()`].
+	///
+	/// # Errors
+	/// Returns an [`InvariantError`] if the cursor is past the end of the collection.
+	pub fn check_invariant(&self) -> Result<(), InvariantError> {
+		let collection_len = self.inner.len();
+
+		if self.pos <= collection_len {
+			Ok(())
+		} else {
+			Err(InvariantError {
+				position: self.pos,
+				collection_len,
+			})
+		}
+	}
+
+	/// Asserts that `0 <= self.position() <= self.get_ref().len()` is upheld. This check is only
+	/// performed when debug assertions are enabled.
+	///
+	/// # Panics
+	/// Panics if debug assertions are enabled, and [`Self::check_invariant()`] returns an error.
+	#[track_caller]
+	pub fn debug_assert_invariant(&self) {
+		if cfg!(debug_assertions)
+			&& let Err(error) = self.check_invariant()
+		{
+			panic!("{error}");
+		}
+	}
+
 	/// Moves the cursor to a new index.
 	///
-	/// It is an error to seek to a position before `0` or after `self.get_ref().len()`. In these
-	/// cases, `None` will be returned and the cursor will not be moved.
+	/// Seeks which would move the cursor before `0` or after `self.get_ref().len()` are handled
+	/// according to the cursor's [`SeekPolicy`]:
+	/// * [`SeekPolicy::Strict`] refuses to move the cursor, and returns an error.
+	/// * [`SeekPolicy::Clamp`] moves the cursor as far as it can, as with
+	///   [`Self::seek_saturating()`].
+	/// * [`SeekPolicy::Wrap`] treats the collection as circular, as with [`Self::seek_wrapping()`].
+	///   If the collection is empty, this acts the same as `SeekPolicy::Strict`.
+	///
+	/// Returns `Ok(new_pos)`, where `new_pos` is the new position of the cursor.
+	///
+	/// # Errors
+	/// If the cursor's seek policy is [`SeekPolicy::Strict`] (or is [`SeekPolicy::Wrap`] and the
+	/// collection is empty), it is an error to seek to a position before `0` or after
+	/// `self.get_ref().len()`. In these cases, an [`OutOfBoundsError`] describing the attempted
+	/// seek will be returned, and the cursor will not be moved.
+	pub fn seek(&mut self, pos: SeekFrom) -> Result<usize, OutOfBoundsError> {
+		// `seek_saturating()` and `seek_wrapping()` record the seek themselves.
+		match self.seek_policy {
+			SeekPolicy::Clamp => return Ok(self.seek_saturating(pos)),
+			SeekPolicy::Wrap => {
+				if let Some(new_pos) = self.seek_wrapping(pos) {
+					return Ok(new_pos);
+				}
+			}
+			SeekPolicy::Strict => self.record_seek(),
+		}
+
+		self.seek_target(self.pos, pos)
+			.inspect(|&new_pos| self.pos = new_pos)
+	}
+
+	/// Applies each seek within `ops` in order, moving the cursor only if every seek stays within
+	/// the bounds of the collection.
+	///
+	/// This ignores the cursor's [`SeekPolicy`], and always acts as though it were
+	/// [`SeekPolicy::Strict`].
+	///
+	/// Returns `Ok(new_pos)`, where `new_pos` is the new position of the cursor.
+	///
+	/// # Errors
+	/// If any seek would move the cursor before `0` or after `self.get_ref().len()`, returns the
+	/// index of that seek within `ops`, along with the error describing it. In this case, the
+	/// cursor will not be moved at all.
+	pub fn batch_seek(&mut self, ops: &[SeekFrom]) -> Result<usize, (usize, OutOfBoundsError)> {
+		self.record_seek();
+		let new_pos = ops
+			.iter()
+			.enumerate()
+			.try_fold(self.pos, |pos, (index, &op)| {
+				self.seek_target(pos, op).map_err(|error| (index, error))
+			})?;
+
+		self.pos = new_pos;
+		Ok(new_pos)
+	}
+
+	/// Moves the cursor towards a new index, stopping at the beginning of the collection or one
+	/// index past the last item if the target is before or after them, respectively.
+	///
+	/// Unlike [`Self::seek()`], this will always move the cursor as far as it can, rather than
+	/// refusing to move. Returns the new position of the cursor.
+	pub fn seek_saturating(&mut self, pos: SeekFrom) -> usize {
+		self.record_seek();
+		let collection_len = self.inner.len();
+
+		let target = match pos {
+			SeekFrom::Start(p) => p,
+			SeekFrom::End(p) => collection_len.saturating_add_signed(p),
+			SeekFrom::Current(p) => self.pos.saturating_add_signed(p),
+		};
+
+		self.pos = target.min(collection_len);
+		self.pos
+	}
+
+	/// Moves the cursor to a new index, treating the collection as circular: seeking past the last
+	/// item wraps around to the beginning of the collection, and seeking before the beginning wraps
+	/// around to the last item. One index past the last item is treated as the same position as the
+	/// beginning of the collection. However, `SeekFrom::Current(0)` never moves the cursor, so that
+	/// it can be used to query the cursor's position even when it is one index past the last item.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the new position of the cursor, or `None` if the
+	/// collection is empty. In the latter case, the cursor will not be moved.
+	pub fn seek_wrapping(&mut self, pos: SeekFrom) -> Option<usize> {
+		self.record_seek();
+		let collection_len = self.inner.len();
+		if collection_len == 0 {
+			return None;
+		}
+
+		self.pos = match pos {
+			SeekFrom::Start(p) => p % collection_len,
+			SeekFrom::End(p) => Self::wrapping_offset(0, p, collection_len),
+			SeekFrom::Current(0) => self.pos,
+			SeekFrom::Current(p) => Self::wrapping_offset(self.pos, p, collection_len),
+		};
+		Some(self.pos)
+	}
+
+	/// Adjusts every saved position to account for the collection having been rotated so that the
+	/// item at the cursor became the first item, so that they keep pointing at the same items, then
+	/// moves the cursor to index `0`.
+	///
+	/// This must be called after rotating.
+	fn finish_rotation_to_front(&mut self) {
+		let (pos, collection_len) = (self.pos, self.inner.len());
+		self.adjust_saved_positions(|saved| {
+			if saved < collection_len {
+				(saved + collection_len - pos) % collection_len
+			} else {
+				saved
+			}
+		});
+		self.pos = 0;
+		#[cfg(feature = "alloc")]
+		self.notify_edit(CursorEventKind::Reorder {
+			start: 0,
+			end: collection_len,
+		});
+		self.trace_edit(format_args!("rotated the item at index {pos} to the front"));
+	}
+
+	/// Logs a structural edit, along with the cursor's position and the collection's length after
+	/// the edit. Without the `log` feature, this does nothing.
+	pub(crate) fn trace_edit(&self, edit: fmt::Arguments<'_>) {
+		#[cfg(feature = "log")]
+		log::trace!(
+			"{edit} (cursor at {}, collection length now {})",
+			self.pos,
+			self.inner.len()
+		);
+		#[cfg(not(feature = "log"))]
+		let _ = edit;
+	}
+
+	/// Shifts every saved position to account for `count` items having been inserted at `index`,
+	/// so that they keep pointing at the same items.
+	///
+	/// As every insertion made through the cursor calls this, it also records and logs the
+	/// insertion.
+	pub(crate) fn shift_saved_positions_for_insert(&mut self, index: usize, count: usize) {
+		self.record_inserts(count);
+		#[cfg(feature = "alloc")]
+		self.notify_edit(CursorEventKind::Insert { index, count });
+		self.trace_edit(format_args!("inserted {count} item(s) at index {index}"));
+		self.adjust_saved_positions(|saved| {
+			if saved >= index {
+				saved.saturating_add(count)
+			} else {
+				saved
+			}
+		});
+	}
+
+	/// Shifts every saved position to account for `count` items having been removed from `index`
+	/// onwards, so that they keep pointing at the same items. Positions which pointed at a removed
+	/// item are moved to `index`, the item which followed the removed items.
+	///
+	/// As every removal made through the cursor calls this, it also records and logs the removal.
+	pub(crate) fn shift_saved_positions_for_remove(&mut self, index: usize, count: usize) {
+		self.record_removals(count);
+		#[cfg(feature = "alloc")]
+		self.notify_edit(CursorEventKind::Remove { index, count });
+		self.trace_edit(format_args!("removed {count} item(s) from index {index}"));
+		let end = index.saturating_add(count);
+		self.adjust_saved_positions(|saved| {
+			if saved >= end {
+				saved - count
+			} else {
+				saved.min(index)
+			}
+		});
+	}
+
+	/// Computes `(base + offset) mod collection_len`, without overflowing.
+	///
+	/// `collection_len` must not be `0`.
+	fn wrapping_offset(base: usize, offset: isize, collection_len: usize) -> usize {
+		let base = base % collection_len;
+		let distance = offset.unsigned_abs() % collection_len;
+
+		if offset >= 0 {
+			// Equivalent to `base + distance`, but wrapping at `collection_len`.
+			match base.checked_sub(collection_len - distance) {
+				Some(wrapped) => wrapped,
+				None => base + distance,
+			}
+		} else {
+			// Equivalent to `base - distance`, but wrapping at `collection_len`.
+			match base.checked_sub(distance) {
+				Some(unwrapped) => unwrapped,
+				None => base + (collection_len - distance),
+			}
+		}
+	}
+
+	/// Computes the position that seeking by `seek_from` would move a cursor at `from_pos` to.
 	///
-	/// Otherwise, this will return `Some(new_pos)`, where `new_pos` is the new position of the
-	/// cursor.
-	// TODO: Change to something like `Result<usize, OutOfBoundsError>`
-	pub fn seek(&mut self, pos: SeekFrom) -> Option<usize> {
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] if the resulting position would be before `0` or after
+	/// `self.get_ref().len()`.
+	fn seek_target(&self, from_pos: usize, seek_from: SeekFrom) -> Result<usize, OutOfBoundsError> {
 		let collection_len = self.inner.len();
 
-		let desired_position = match pos {
+		let target = match seek_from {
 			SeekFrom::Start(p) => Some(p),
 			SeekFrom::End(p) => collection_len.checked_add_signed(p),
-			SeekFrom::Current(p) => self.pos.checked_add_signed(p),
+			SeekFrom::Current(p) => from_pos.checked_add_signed(p),
 		};
 
-		desired_position
-			.filter(|&pos| pos <= collection_len)
-			.inspect(|&new_pos| self.pos = new_pos)
+		let error = match target {
+			Some(target) if target <= collection_len => return Ok(target),
+			_ => OutOfBoundsError {
+				seek_from,
+				target,
+				collection_len,
+			},
+		};
+
+		#[cfg(feature = "log")]
+		log::trace!("{error} (cursor at {from_pos})");
+		Err(error)
+	}
+
+	/// Gets a guard providing mutable access to the underlying collection. When the guard is
+	/// dropped, the cursor is clamped back within the bounds of the collection, as if by
+	/// [`Self::clamp_to_end()`].
+	///
+	/// This is a safer alternative to [`Self::get_mut()`] when the collection's length may be
+	/// changed, as the guard restores `0 <= self.position() <= self.get_ref().len()` for you.
+	///
+	/// As the clamping happens on drop, the cursor's invariant is restored even after an early
+	/// return or a panic.
+	///
+	/// With the `debug-invariants` feature, mutably accessing the collection through the guard is
+	/// treated in the same way as calling [`Self::get_mut()`].
+	pub fn get_mut_guarded(&mut self) -> ClampGuard<'_, Tape> {
+		ClampGuard::new(self)
+	}
+
+	/// Gets a guard providing mutable access to the underlying collection, which clamps the cursor
+	/// when dropped.
+	///
+	/// This is the same as [`Self::get_mut_guarded()`].
+	#[deprecated(note = "renamed to `get_mut_guarded`")]
+	pub fn clamp_guard(&mut self) -> ClampGuard<'_, Tape> {
+		self.get_mut_guarded()
+	}
+
+	/// Runs `f` with a mutable reference to the underlying collection, then clamps the cursor back
+	/// within the bounds of the collection, as if by [`Self::clamp_to_end()`]. Returns the value
+	/// returned by `f`.
+	///
+	/// This is a safer alternative to [`Self::get_mut()`] when the collection's length may be
+	/// changed. To clamp the cursor to the last item instead, use [`Self::edit_with()`].
+	///
+	/// If `f` panics, the cursor is not clamped. Use [`Self::get_mut_guarded()`] if the cursor must
+	/// be clamped even then.
+	///
+	/// With the `debug-invariants` feature, calling this is treated in the same way as calling
+	/// [`Self::get_mut()`], whether or not `f` edits the collection.
+	pub fn edit<R, F: FnOnce(&mut Tape) -> R>(&mut self, f: F) -> R {
+		self.edit_with(ClampPolicy::ToEnd, f)
+	}
+
+	/// Runs `f` with a mutable reference to the underlying collection, then clamps the cursor back
+	/// within the bounds of the collection according to `clamp_policy`. Returns the value returned
+	/// by `f`.
+	///
+	/// If `f` panics, the cursor is not clamped. See [`Self::edit()`] for more details.
+	pub fn edit_with<R, F: FnOnce(&mut Tape) -> R>(
+		&mut self,
+		clamp_policy: ClampPolicy,
+		f: F,
+	) -> R {
+		#[cfg(feature = "debug-invariants")]
+		self.generation.record_untracked_edit();
+
+		let result = f(&mut self.inner);
+
+		match clamp_policy {
+			ClampPolicy::ToEnd => self.clamp_to_end(),
+			ClampPolicy::ToLastItem => self.clamp_to_last_item(),
+		}
+		result
+	}
+
+	/// Gets a guard providing access to this cursor. When the guard is dropped, the cursor is moved
+	/// back to the position it was at when the guard was created, unless
+	/// [`PositionGuard::commit()`] was called. If the collection has shrunk in the meantime, the
+	/// cursor is instead moved to one index past the last item.
+	///
+	/// This allows speculative movement to be undone on every error path, including early returns
+	/// and panics.
+	pub fn position_guard(&mut self) -> PositionGuard<'_, Tape> {
+		PositionGuard::new(self)
 	}
 
 	/// Clamps the cursor to the index of the last item, or `0` if no items exist. If the cursor is
@@ -96,9 +895,7 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 	pub fn clamp_to_last_item(&mut self) {
 		// `usize`, by its nature, cannot be below `0`. Thus, we only need to know which is the
 		// smaller value: the collection length, or the head position
-		self.pos = self
-			.pos
-			.min(self.inner.len().checked_sub(1).unwrap_or_default());
+		self.pos = self.pos.min(self.inner.len().saturating_sub(1));
 	}
 
 	/// Clamps the cursor to one index past the last item. If the cursor is before or at that index,
@@ -109,828 +906,4745 @@ impl<Tape: IndexableCollection> CollectionCursor<Tape> {
 		self.pos = self.pos.min(self.inner.len());
 	}
 
+	/// Clamps the cursor back within the bounds of the collection, restoring
+	/// `0 <= self.position() <= self.get_ref().len()`. If the cursor is already within those
+	/// bounds, nothing will happen.
+	///
+	/// This is equivalent to [`Self::clamp_to_end()`].
+	pub fn clamp_into_bounds(&mut self) {
+		self.clamp_to_end();
+	}
+
+	/// Replaces the inner collection with `new_inner`, returning the old collection. The seek
+	/// policy is kept.
+	///
+	/// The cursor, checkpoints, and marks keep their positions if they are within the bounds of the
+	/// new collection. Otherwise, they are moved to one index past the last item of the new
+	/// collection. Spans created beforehand should not be used with the new collection; with the
+	/// `debug-invariants` feature, doing so will panic.
+	///
+	/// This allows a cursor's buffer to be recycled without deconstructing the cursor through
+	/// [`Self::into_inner()`] and building a new one.
+	pub fn replace_inner(&mut self, new_inner: Tape) -> Tape {
+		let old_inner = core::mem::replace(&mut self.inner, new_inner);
+		let collection_len = self.inner.len();
+		self.adjust_saved_positions(|saved| saved.min(collection_len));
+		self.clamp_to_end();
+		#[cfg(feature = "alloc")]
+		{
+			self.notify_edit(CursorEventKind::Remove {
+				index: 0,
+				count: old_inner.len(),
+			});
+			self.notify_edit(CursorEventKind::Insert {
+				index: 0,
+				count: collection_len,
+			});
+		}
+		old_inner
+	}
+
+	/// Replaces the inner collection with `new_inner`, returning the old collection.
+	///
+	/// This is the same as [`Self::replace_inner()`]: the cursor, checkpoints, and marks are
+	/// clamped to one index past the last item of the new collection if they are past it.
+	#[deprecated(note = "use `replace_inner` instead")]
+	pub fn replace_tape(&mut self, new_inner: Tape) -> Tape {
+		self.replace_inner(new_inner)
+	}
+
+	/// Moves the cursor back to the position saved by [`Self::save_checkpoint()`]. If the
+	/// collection has shrunk since then, the cursor is instead moved to one index past the last
+	/// item. The checkpoint is kept, so it may be restored again later.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the new position of the cursor, or `None` if no
+	/// checkpoint is saved. In the latter case, the cursor will not be moved.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if the collection was edited outside of the
+	/// cursor since the position was saved (such as through [`Self::get_mut()`]), and the position
+	/// is still within the bounds of the collection, as it may no longer point at the same item.
+	/// A position past the end of the collection is clamped as described above instead.
+	pub fn restore_checkpoint(&mut self) -> Option<usize> {
+		let checkpoint = self.checkpoint?;
+		#[cfg(feature = "debug-invariants")]
+		self.assert_saved_position_valid(checkpoint);
+
+		self.pos = checkpoint.min(self.inner.len());
+		Some(self.pos)
+	}
+
+	/// Runs `f` with this cursor, moving the cursor back to its original position if `f` returns
+	/// an error. This allows a parser to attempt one alternative, and fall back to another if it
+	/// fails, without saving and restoring the position by hand.
+	///
+	/// If the collection has shrunk such that the original position is no longer valid, the cursor
+	/// is instead moved to one index past the last item. Changes `f` made to the collection itself
+	/// are not undone.
+	///
+	/// # Errors
+	/// Returns the error returned by `f`, if any.
+	pub fn try_parse<R, E, F: FnOnce(&mut Self) -> Result<R, E>>(&mut self, f: F) -> Result<R, E> {
+		let start = self.pos;
+		let result = f(self);
+		if result.is_err() {
+			self.pos = start.min(self.inner.len());
+		}
+		result
+	}
+
 	/// Moves the cursor to the beginning of the collection.
 	///
 	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Start(0))`.
 	pub fn seek_to_start(&mut self) {
+		self.record_seek();
 		self.pos = 0;
 	}
 
-	/// Moves the cursor backwards one item. Returning `true` if the move was successful, or `false`
-	/// if we're already at the beginning of the collection.
+	/// Moves the cursor backwards one item. Returns `true` if the cursor's position changed, or
+	/// `false` otherwise (such as if we're already at the beginning of the collection).
 	///
-	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(-1))`.
+	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(-1))`, and so
+	/// respects the cursor's [`SeekPolicy`].
 	pub fn seek_backward_one(&mut self) -> bool {
-		self.seek_relative(-1).is_some()
+		let old_pos = self.pos;
+		self.seek_relative(-1)
+			.is_ok_and(|new_pos| new_pos != old_pos)
 	}
 
 	/// Moves the cursor relative to the current position. The return value is the same as the one
 	/// returned for [`Self::seek()`].
 	///
-	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(offset))`.
-	// TODO: Change to something like `Result<usize, OutOfBoundsError>`
-	pub fn seek_relative(&mut self, offset: isize) -> Option<usize> {
+	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(offset))`, and so
+	/// respects the cursor's [`SeekPolicy`].
+	///
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] in the same cases as [`Self::seek()`]. In this case, the
+	/// cursor will not be moved.
+	pub fn seek_relative(&mut self, offset: isize) -> Result<usize, OutOfBoundsError> {
 		self.seek(SeekFrom::Current(offset))
 	}
 
-	/// Moves the cursor forwards one item, if an item exists. Returns `true` if the move was
-	/// successful, and `false` if we're already at the end of the collection.
+	/// Shifts the cursor's position by `delta` indices. The return value is the same as the one
+	/// returned for [`Self::seek()`].
+	///
+	/// This moves the cursor in the same way as [`Self::seek_relative()`], but is intended for
+	/// reconciling the cursor's position after modifying the collection through
+	/// [`Self::get_mut()`] (for example, moving the cursor forward by `k` after inserting `k` items
+	/// before it), rather than for navigating the collection. For this reason, it ignores the
+	/// cursor's [`SeekPolicy`], and always acts as though it were [`SeekPolicy::Strict`].
+	///
+	/// # Errors
+	/// Returns an [`OutOfBoundsError`] if the new position would be before `0` or after
+	/// `self.get_ref().len()`. In this case, the cursor will not be moved.
+	pub fn shift_position_by(&mut self, delta: isize) -> Result<usize, OutOfBoundsError> {
+		self.record_seek();
+		self.seek_target(self.pos, SeekFrom::Current(delta))
+			.inspect(|&new_pos| self.pos = new_pos)
+	}
+
+	/// Moves the cursor forwards one item. Returns `true` if the cursor's position changed, or
+	/// `false` otherwise (such as if we're already at the end of the collection).
 	///
-	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(1))`.
+	/// This is a convenience method, equivalent to `self.seek(SeekFrom::Current(1))`, and so
+	/// respects the cursor's [`SeekPolicy`].
 	pub fn seek_forward_one(&mut self) -> bool {
-		self.seek_relative(1).is_some()
+		let old_pos = self.pos;
+		self.seek_relative(1)
+			.is_ok_and(|new_pos| new_pos != old_pos)
 	}
 
-	/// Moves the cursor to the index of the last item, or to `0` if no items exist.
+	/// Moves the cursor forwards one item, wrapping around to the beginning of the collection if
+	/// the cursor is on the last item. Returns `true` if the move was successful, or `false` if the
+	/// collection is empty.
 	///
-	/// This is a convenience method, equivalent to `self.seek(SeekFrom::End(-1))`.
-	pub fn seek_to_last_item(&mut self) {
-		self.pos = self.inner.len().checked_sub(1).unwrap_or_default();
+	/// This is a convenience method, equivalent to `self.seek_wrapping(SeekFrom::Current(1))`.
+	pub fn seek_forward_one_wrapping(&mut self) -> bool {
+		self.seek_wrapping(SeekFrom::Current(1)).is_some()
 	}
 
-	/// Moves the cursor to one index past the last item.
+	/// Moves the cursor backwards one item, wrapping around to the last item if the cursor is at
+	/// the beginning of the collection. Returns `true` if the move was successful, or `false` if
+	/// the collection is empty.
 	///
-	/// This is a convenience method, equivalent to `self.seek(SeekFrom::End(0))`.
-	pub fn seek_to_end(&mut self) {
-		self.pos = self.inner.len();
+	/// This is a convenience method, equivalent to `self.seek_wrapping(SeekFrom::Current(-1))`.
+	pub fn seek_backward_one_wrapping(&mut self) -> bool {
+		self.seek_wrapping(SeekFrom::Current(-1)).is_some()
 	}
 
-	/// Returns a reference to the element pointed at by the cursor.
+	/// Moves the cursor forwards exactly `n` items. Returns `true` if the move was successful, or
+	/// `false` if doing so would put the cursor more than one index past the last item, in which
+	/// case the cursor will not be moved.
 	///
-	/// Returns `None` if `self.position() >= self.get_ref().len()`.
-	pub fn get_item_at_cursor(&self) -> Option<&Tape::Item> {
-		self.inner.get_item(self.pos)
+	/// Unlike `self.seek(SeekFrom::Current(n))`, this accepts any `usize`, and will never move the
+	/// cursor only part of the way.
+	pub fn try_seek_forward_n(&mut self, n: usize) -> bool {
+		self.record_seek();
+		match self.pos.checked_add(n) {
+			Some(new_pos) if new_pos <= self.inner.len() => {
+				self.pos = new_pos;
+				true
+			}
+			_ => false,
+		}
 	}
-}
 
-impl<Tape: IndexableCollectionMut> CollectionCursor<Tape> {
-	/// Returns a mutable reference to the element pointed at by the cursor.
+	/// Moves the cursor backwards exactly `n` items. Returns `true` if the move was successful, or
+	/// `false` if doing so would put the cursor before the beginning of the collection, in which
+	/// case the cursor will not be moved.
 	///
-	/// Returns `None` if the cursor is out-of-bounds.
-	pub fn get_item_at_cursor_mut(&mut self) -> Option<&mut Tape::Item> {
-		self.inner.get_item_mut(self.pos)
+	/// Unlike `self.seek(SeekFrom::Current(-n))`, this accepts any `usize`, and will never move the
+	/// cursor only part of the way.
+	pub fn try_seek_backward_n(&mut self, n: usize) -> bool {
+		self.record_seek();
+		match self.pos.checked_sub(n) {
+			Some(new_pos) if new_pos <= self.inner.len() => {
+				self.pos = new_pos;
+				true
+			}
+			_ => false,
+		}
 	}
 
-	/// Sets the slot at the cursor to `item`.
+	/// Moves the cursor forwards by up to `n` items, stopping early at one index past the last
+	/// item. This mirrors [`Iterator::advance_by()`].
 	///
-	/// # Panics
-	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
-	/// inner collection, but will usually occur if `self.position() >= self.get_ref().len()`.
-	pub fn set_item_at_cursor(&mut self, item: Tape::Item) {
-		self.inner.set_item(self.pos, item);
+	/// Returns `Ok(())` if the cursor moved forwards by exactly `n` items.
+	///
+	/// # Errors
+	/// If the cursor stopped early, returns the number of steps which could not be taken. The
+	/// cursor is still moved as far as it could go.
+	pub fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+		self.record_seek();
+		let steps = n.min(self.inner.len().saturating_sub(self.pos));
+		self.pos += steps;
+		NonZeroUsize::new(n - steps).map_or(Ok(()), Err)
 	}
-}
 
-impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
-	/// Removes all elements within the inner collection, and returns the cursor to the index `0`.
-	pub fn clear(&mut self) {
-		self.inner.clear();
-		self.pos = 0;
+	/// Moves the cursor backwards by up to `n` items, stopping early at the beginning of the
+	/// collection. This mirrors [`Iterator::advance_by()`], but in the opposite direction.
+	///
+	/// Returns `Ok(())` if the cursor moved backwards by exactly `n` items.
+	///
+	/// # Errors
+	/// If the cursor stopped early, returns the number of steps which could not be taken. The
+	/// cursor is still moved as far as it could go.
+	pub fn retreat_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+		self.record_seek();
+		let steps = n.min(self.pos);
+		self.pos -= steps;
+		NonZeroUsize::new(n - steps).map_or(Ok(()), Err)
 	}
 
-	/// Inserts `item` at the cursor, shifting the following elements to the right by one index.
+	/// Moves the cursor to the index of the last item, or to `0` if no items exist.
 	///
-	/// # Panics
-	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
-	/// inner collection, but will usually occur if `self.position() > self.get_ref().len()`.
-	pub fn insert_item_at_cursor(&mut self, item: Tape::Item) {
-		self.inner.insert_item(self.pos, item);
+	/// Unlike `self.seek(SeekFrom::End(-1))`, this ignores the cursor's [`SeekPolicy`], and always
+	/// succeeds, even if the collection is empty.
+	pub fn seek_to_last_item(&mut self) {
+		self.record_seek();
+		self.pos = self.inner.len().saturating_sub(1);
 	}
 
-	/// If `self.position() == self.get_ref().len()`, then insert `item` at the cursor. Otherwise,
-	/// set the slot at the cursor to `item`.
+	/// Moves the cursor to one index past the last item.
 	///
-	/// This is equivalent to [`Self::set_item_at_cursor`], except when
-	/// `self.position() == self.get_ref().len()`, then it's equivalent to
-	/// [`Self::insert_item_at_cursor`].
+	/// Unlike `self.seek(SeekFrom::End(0))`, this ignores the cursor's [`SeekPolicy`]. Under
+	/// [`SeekPolicy::Wrap`], that seek would instead move the cursor to `0`.
+	pub fn seek_to_end(&mut self) {
+		self.record_seek();
+		self.pos = self.inner.len();
+	}
+
+	/// Returns a reference to the element pointed at by the cursor.
 	///
-	/// # Panics
-	/// Panics if the set/insert operation panics. The circumstances for a panic are defined by the
-	/// inner collection, but will usually occur if `self.position() > self.get_ref().len()`.
-	pub fn set_or_insert_item_at_cursor(&mut self, item: Tape::Item) {
-		if self.is_cursor_at_end() {
-			self.insert_item_at_cursor(item);
-		} else {
-			self.set_item_at_cursor(item);
-		}
+	/// Returns `None` if `self.position() >= self.get_ref().len()`.
+	pub fn get_item_at_cursor(&self) -> Option<&Tape::Item> {
+		self.inner.get_item(self.pos)
 	}
 
-	/// Removes and returns the item at the cursor.
+	/// Returns a reference to the element pointed at by the cursor.
 	///
-	/// Returns `None` if `self.position() >= self.get_ref().len()`, or if the remove operation
-	/// would normally panic.
-	pub fn remove_item_at_cursor(&mut self) -> Option<Tape::Item> {
-		// Note: We don't have to worry about moving the cursor. If the cursor is on the last item,
-		// removing will put it one index past the end, which is still within the valid area for the
-		// cursor to be. Meanwhile, if it's past the end, no item will be removed.
-		self.inner.remove_item(self.pos)
+	/// Returns `None` if `self.position() >= self.get_ref().len()`. This is equivalent to
+	/// [`Self::get_item_at_cursor()`].
+	pub fn peek(&self) -> Option<&Tape::Item> {
+		self.get_item_at_cursor()
 	}
-}
 
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum SeekFrom {
-	/// Moves the cursor to the provided index.
+	/// Returns a reference to the element `offset` indices away from the cursor, without moving
+	/// the cursor. `peek_offset(0)` is equivalent to [`Self::peek()`].
 	///
-	/// # Examples
-	/// * `SeekFrom::Start(0)` will move the cursor to the first item
-	/// * `SeekFrom::Start(5)` will move the cursor to the sixth item
-	Start(usize),
-	/// Moves the cursor to the cassette's length (as provided by [`IndexableCollection::len`]) plus
-	/// the provided number of indices.
+	/// Returns `None` if the index is before `0` or at/after `self.get_ref().len()`.
+	pub fn peek_offset(&self, offset: isize) -> Option<&Tape::Item> {
+		self.inner.get_item(self.pos.checked_add_signed(offset)?)
+	}
+
+	/// Returns references to the element just before the cursor, the element pointed at by the
+	/// cursor, and the element just after it, in that order. Each is `None` if no such element
+	/// exists.
 	///
-	/// It is an error to seek before the first index, or more than one index past the last item.
+	/// The cursor is not moved.
+	#[allow(
+		clippy::type_complexity,
+		reason = "A type alias would only obscure what each element of the tuple is"
+	)]
+	pub fn neighbors(
+		&self,
+	) -> (
+		Option<&Tape::Item>,
+		Option<&Tape::Item>,
+		Option<&Tape::Item>,
+	) {
+		let previous = self
+			.pos
+			.checked_sub(1)
+			.and_then(|index| self.inner.get_item(index));
+		let current = self.inner.get_item(self.pos);
+		let next = current.and_then(|_| self.inner.get_item(self.pos + 1));
+		(previous, current, next)
+	}
+
+	/// Returns a reference to the element pointed at by the cursor, then moves the cursor forwards
+	/// one item.
 	///
-	/// # Examples
-	/// * `SeekFrom::End(-1)` will move the cursor to the last item, if one exists
-	/// * `SeekFrom::End(0)` will move the cursor to the index just after the last item
-	End(isize),
-	/// Moves the cursor to the current position (as provided by [`CollectionCursor::position`])
-	/// plus the provided number of indices.
+	/// Returns `None` if `self.position() >= self.get_ref().len()`. In this case, the cursor will
+	/// not be moved.
+	pub fn next_item(&mut self) -> Option<&Tape::Item> {
+		let item = self.inner.get_item(self.pos)?;
+		self.pos += 1;
+		// `self.record_reads()` would conflict with the borrow of `item`.
+		#[cfg(feature = "metrics")]
+		{
+			self.metrics.reads = self.metrics.reads.saturating_add(1);
+		}
+		Some(item)
+	}
+
+	/// Moves the cursor backwards one item, then returns a reference to the element now pointed at
+	/// by the cursor.
 	///
-	/// It is an error to seek before the first index, or more than one index past the last item.
+	/// Returns `None` if the cursor is at the beginning of the collection. In this case, the
+	/// cursor will not be moved.
+	pub fn prev_item(&mut self) -> Option<&Tape::Item> {
+		let new_pos = self.pos.checked_sub(1)?;
+		let item = self.inner.get_item(new_pos)?;
+		self.pos = new_pos;
+		#[cfg(feature = "metrics")]
+		{
+			self.metrics.reads = self.metrics.reads.saturating_add(1);
+		}
+		Some(item)
+	}
+
+	/// Returns a reference to the element at `index`, regardless of the cursor's position.
 	///
-	/// # Examples
-	/// * `SeekFrom::Current(-2)` will move the cursor back two indices
-	/// * `SeekFrom::Current(0)` won't move anywhere
-	/// * `SeekFrom::Current(5)` will move the cursor forward five indices
-	Current(isize),
-}
+	/// Returns `None` if `index >= self.get_ref().len()`. The cursor is not moved.
+	pub fn get_ref_at(&self, index: usize) -> Option<&Tape::Item> {
+		self.inner.get_item(index)
+	}
 
-#[allow(
-	clippy::len_without_is_empty,
-	reason = "While is_empty would normally be useful, we don't have a use for it here"
-)]
-pub trait IndexableCollection {
-	/// The type of item this container contains.
-	type Item;
+	/// Returns a reference to the first element of the collection, regardless of the cursor's
+	/// position.
+	///
+	/// Returns `None` if the collection is empty. The cursor is not moved.
+	pub fn first(&self) -> Option<&Tape::Item> {
+		self.inner.get_item(0)
+	}
 
-	/// Gets the number of items this container currently contains.
-	fn len(&self) -> usize;
-	/// Gets a reference to the item at index `index`. Returns `None` if no item exists at `index`.
-	fn get_item(&self, index: usize) -> Option<&Self::Item>;
-}
+	/// Returns a reference to the last element of the collection, regardless of the cursor's
+	/// position.
+	///
+	/// Returns `None` if the collection is empty. The cursor is not moved.
+	pub fn last(&self) -> Option<&Tape::Item> {
+		self.inner.get_item(self.inner.len().checked_sub(1)?)
+	}
 
-pub trait IndexableCollectionMut: IndexableCollection {
-	/// Gets a mutable reference to the item at index `index`. Returns `None` if no item exists at
-	/// `index`.
-	fn get_item_mut(&mut self, index: usize) -> Option<&mut Self::Item>;
-	/// Sets an item at a specific index.
+	/// Moves the cursor relative to the current position, stopping at the beginning of the
+	/// collection or one index past the last item if the move would go past either of them.
 	///
-	/// This is allowed (and generally expected) to panic if `index >= self.len()`. However, if it
-	/// doesn't, then ensure you are following the "rule of least surprise" - whether through
-	/// documentation or otherwise.
-	fn set_item(&mut self, index: usize, element: Self::Item);
-}
+	/// Returns the signed distance the cursor actually moved, along with a reference to the element
+	/// now pointed at by the cursor (or `None` if the cursor is one index past the last item).
+	pub fn scroll_and_read(&mut self, offset: isize) -> (isize, Option<&Tape::Item>) {
+		let old_pos = self.pos;
+		self.seek_saturating(SeekFrom::Current(offset));
 
-pub trait IndexableCollectionResizable: IndexableCollectionMut {
-	/// Inserts an item at a specific index, moving the item at the index and all items after it
-	/// one index forward.
+		// As long as the cursor started within the bounds of the collection, the distance moved is
+		// never larger than `offset`, so this cannot overflow.
+		let distance_moved = self.pos.wrapping_sub(old_pos).cast_signed();
+		(distance_moved, self.get_item_at_cursor())
+	}
+
+	/// Searches backwards from the item just before the cursor, returning the index of the first
+	/// item for which `pred` returns `true`. Items at or after the cursor are not considered.
 	///
-	/// This is allowed (and generally expected) to panic if `index > self.len()`. However, if it
-	/// doesn't, then ensure you are following the "rule of least surprise" - whether through
-	/// documentation or otherwise.
-	fn insert_item(&mut self, index: usize, element: Self::Item);
-	/// Removes the item at index `index` from the container, and returns the item, or `None` if no
-	/// item exists at index `index`.
+	/// The cursor is not moved. Returns `None` if no item before the cursor matches.
+	pub fn rposition_before_cursor<F: FnMut(&Tape::Item) -> bool>(
+		&self,
+		mut pred: F,
+	) -> Option<usize> {
+		let end = self.pos.min(self.inner.len());
+		(0..end)
+			.rev()
+			.find(|&index| self.inner.get_item(index).is_some_and(&mut pred))
+	}
+
+	/// Returns the index of and a reference to the item with the maximum key, as computed by `f`.
+	/// If several items are equally maximum, the first of them is returned.
 	///
-	/// You must ensure that removing at an index past the end of the collection does not panic. If
-	/// the normal `remove()` method of a collection would panic given an invalid index, your
-	/// implementation must check and return `None` in those instances.
-	fn remove_item(&mut self, index: usize) -> Option<Self::Item>;
-	/// Clears the container's contents.
-	fn clear(&mut self);
-}
+	/// The whole collection is considered, regardless of the cursor's position. The cursor is not
+	/// moved. Returns `None` if the collection is empty.
+	pub fn max_by_key<K: Ord, F: FnMut(&Tape::Item) -> K>(
+		&self,
+		f: F,
+	) -> Option<(usize, &Tape::Item)> {
+		self.extreme_by_key(f, Ordering::Greater)
+	}
 
-#[cfg(test)]
-mod collection_cursor_tests {
-	extern crate alloc;
+	/// Returns the index of and a reference to the item with the minimum key, as computed by `f`.
+	/// If several items are equally minimum, the first of them is returned.
+	///
+	/// The whole collection is considered, regardless of the cursor's position. The cursor is not
+	/// moved. Returns `None` if the collection is empty.
+	pub fn min_by_key<K: Ord, F: FnMut(&Tape::Item) -> K>(
+		&self,
+		f: F,
+	) -> Option<(usize, &Tape::Item)> {
+		self.extreme_by_key(f, Ordering::Less)
+	}
 
-	use super::*;
-	use alloc::vec::Vec;
+	/// Shared implementation of [`Self::max_by_key()`] and [`Self::min_by_key()`]. An item only
+	/// replaces the current best item if its key compares as `replace_when` against the best key,
+	/// which ensures the first item wins on ties.
+	fn extreme_by_key<K: Ord, F: FnMut(&Tape::Item) -> K>(
+		&self,
+		mut f: F,
+		replace_when: Ordering,
+	) -> Option<(usize, &Tape::Item)> {
+		let mut best: Option<(K, usize, &Tape::Item)> = None;
 
-	type TestVec = Vec<i32>;
-	type TestCollection = CollectionCursor<TestVec>;
+		for index in 0..self.inner.len() {
+			let Some(item) = self.inner.get_item(index) else {
+				continue;
+			};
 
-	fn test_vec() -> TestVec {
-		let res = Vec::from([0, 1, 2, 3, 4, 5, 9, 8, 7, 6]);
+			let key = f(item);
+			if best
+				.as_ref()
+				.is_none_or(|(best_key, ..)| key.cmp(best_key) == replace_when)
+			{
+				best = Some((key, index, item));
+			}
+		}
 
-		// Ensure that the length is a known value.
-		// IF YOU CHANGE THIS, ENSURE TESTS ARE CHANGED TO MATCH.
-		assert_eq!(res.len(), 10);
+		best.map(|(_, index, item)| (index, item))
+	}
 
-		res
+	/// Returns an iterator over the indices from the cursor's position up to (but not including)
+	/// the length of the collection.
+	///
+	/// The cursor is not moved.
+	pub fn indices_from_cursor(
+		&self,
+	) -> impl ExactSizeIterator<Item = usize> + DoubleEndedIterator {
+		let collection_len = self.inner.len();
+		self.pos.min(collection_len)..collection_len
 	}
 
-	fn test_collection() -> TestCollection {
-		let res = CollectionCursor {
-			inner: self::test_vec(),
-			pos: Default::default(),
-		};
+	/// Returns an iterator over the items from the cursor's position up to the end of the
+	/// collection.
+	///
+	/// The cursor is not moved.
+	pub fn iter_remaining(&self) -> Iter<'_, Tape> {
+		Iter::new(&self.inner, self.pos, self.inner.len())
+	}
 
-		// Ensure that the cursor position is a known value.
-		// IF YOU CHANGE THIS, ENSURE TESTS ARE CHANGED TO MATCH.
-		assert_eq!(res.pos, Default::default());
+	/// Returns an iterator over the items before the cursor's position - that is, the items which
+	/// the cursor has already moved past.
+	///
+	/// The cursor is not moved.
+	pub fn iter_consumed(&self) -> Iter<'_, Tape> {
+		Iter::new(&self.inner, 0, self.pos)
+	}
 
-		res
+	/// Returns an iterator over every `step`-th item, starting with the item pointed at by the
+	/// cursor, up to the end of the collection.
+	///
+	/// The cursor is not moved.
+	///
+	/// # Panics
+	/// Panics if `step` is `0`.
+	pub fn iter_step_by_from_cursor(&self, step: usize) -> impl Iterator<Item = &Tape::Item> {
+		(self.pos..self.inner.len())
+			.step_by(step)
+			.filter_map(|index| self.inner.get_item(index))
 	}
 
-	#[test]
-	fn new() {
-		let new_collection = CollectionCursor::new(self::test_vec());
-		let test_collection = self::test_collection();
+	/// Returns an iterator over overlapping pairs of adjacent items, starting with the pair of the
+	/// item pointed at by the cursor and the item after it. The last pair yielded will be the last
+	/// two items of the collection.
+	///
+	/// Yields nothing if fewer than two items remain from the cursor onwards. The cursor is not
+	/// moved.
+	pub fn pairs_from_cursor(&self) -> impl Iterator<Item = (&Tape::Item, &Tape::Item)> {
+		(self.pos..self.inner.len().saturating_sub(1)).filter_map(|index| {
+			Some((self.inner.get_item(index)?, self.inner.get_item(index + 1)?))
+		})
+	}
 
-		assert_eq!(new_collection, test_collection);
+	/// Moves the cursor forwards past every consecutive item for which `pred` returns `true`,
+	/// starting with the item pointed at by the cursor. The cursor stops on the first item for
+	/// which `pred` returns `false`, or one index past the last item if every item matched.
+	///
+	/// Returns the number of items skipped.
+	pub fn skip_forward_while<F: FnMut(&Tape::Item) -> bool>(&mut self, mut pred: F) -> usize {
+		self.record_seek();
+		let start = self.pos;
+		while self.inner.get_item(self.pos).is_some_and(&mut pred) {
+			self.pos += 1;
+		}
+		self.pos - start
 	}
 
-	#[test]
-	fn position() {
-		let mut collection = self::test_collection();
-		assert_eq!(collection.position(), 0);
+	/// Moves the cursor forwards past every consecutive item for which `pred` returns `true`.
+	///
+	/// This is the same as [`Self::skip_forward_while()`].
+	///
+	/// Returns the number of items skipped.
+	pub fn skip_while<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> usize {
+		self.skip_forward_while(pred)
+	}
 
-		collection.pos = 5;
-		assert_eq!(collection.position(), 5);
+	/// Moves the cursor backwards past every consecutive item for which `pred` returns `true`,
+	/// starting with the item just before the cursor. The cursor stops just after the first item
+	/// for which `pred` returns `false`, or at the beginning of the collection if every item
+	/// matched.
+	///
+	/// Returns the number of items skipped.
+	pub fn skip_backward_while<F: FnMut(&Tape::Item) -> bool>(&mut self, mut pred: F) -> usize {
+		self.record_seek();
+		let start = self.pos;
+		while let Some(index) = self.pos.checked_sub(1)
+			&& self.inner.get_item(index).is_some_and(&mut pred)
+		{
+			self.pos = index;
+		}
+		start - self.pos
+	}
 
-		collection.pos = usize::MAX;
-		assert_eq!(collection.position(), usize::MAX);
+	/// Searches forwards from `origin` for the first item for which `pred` returns `true`, and
+	/// moves the cursor to it.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the matching item, or `None` if no
+	/// item matches. In the latter case, the cursor will not be moved.
+	pub fn find<F: FnMut(&Tape::Item) -> bool>(
+		&mut self,
+		origin: SearchOrigin,
+		mut pred: F,
+	) -> Option<usize> {
+		self.record_seek();
+		let start = match origin {
+			SearchOrigin::Start => 0,
+			SearchOrigin::Cursor => self.pos,
+		};
+
+		let found = (start..self.inner.len())
+			.find(|&index| self.inner.get_item(index).is_some_and(&mut pred))?;
+		self.pos = found;
+		Some(found)
 	}
 
-	#[test]
-	fn get_ref() {
-		let collection = self::test_collection();
-		assert_eq!(collection.get_ref(), &self::test_vec());
+	/// Searches forwards from the cursor for the first item for which `pred` returns `true`, and
+	/// moves the cursor to it. The item pointed at by the cursor is included in the search.
+	///
+	/// This is the same as calling [`Self::find()`] with [`SearchOrigin::Cursor`].
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the matching item, or `None` if no
+	/// item matches. In the latter case, the cursor will not be moved.
+	pub fn seek_to_match<F: FnMut(&Tape::Item) -> bool>(&mut self, pred: F) -> Option<usize> {
+		self.find(SearchOrigin::Cursor, pred)
 	}
 
-	#[test]
-	fn get_mut() {
-		let mut collection = self::test_collection();
-		assert_eq!(collection.get_mut(), &mut self::test_vec());
+	/// Searches forwards from the cursor for the first item for which `pred` returns `true`,
+	/// without moving the cursor.
+	///
+	/// Returns `Some(distance)`, where `distance` is the number of indices between the cursor and
+	/// the matching item (`0` if the item at the cursor matches), or `None` if no item matches.
+	pub fn distance_to_next<F: FnMut(&Tape::Item) -> bool>(&self, mut pred: F) -> Option<usize> {
+		(self.pos..self.inner.len())
+			.position(|index| self.inner.get_item(index).is_some_and(&mut pred))
 	}
 
-	#[test]
-	fn into_inner() {
-		let collection = self::test_collection();
-		assert_eq!(collection.into_inner(), self::test_vec());
+	/// Folds every item from the cursor onwards into an accumulator, starting with `init`, without
+	/// moving the cursor.
+	///
+	/// If the cursor is not pointing at an item, returns `init`.
+	pub fn fold_from_cursor<B, F: FnMut(B, &Tape::Item) -> B>(&self, init: B, f: F) -> B {
+		(self.pos..self.inner.len())
+			.filter_map(|index| self.inner.get_item(index))
+			.fold(init, f)
 	}
 
-	#[test]
-	fn is_cursor_at_end() {
-		let mut collection = self::test_collection();
+	/// Binary searches the collection with a comparator function, and moves the cursor to the
+	/// result. The comparator should return the ordering of the given item relative to the item
+	/// being searched for.
+	///
+	/// The collection is expected to be sorted in an order consistent with `f`. If it is not, the
+	/// result is unspecified, but the cursor will still be left at a valid position.
+	///
+	/// Returns `Ok(index)` if a matching item was found, where `index` is the index of the match.
+	/// If several items match, any one of them may be returned. In either case, the cursor is
+	/// moved to the returned index.
+	///
+	/// # Errors
+	/// If no matching item was found, returns `Err(index)`, where `index` is the position at which
+	/// a matching item could be inserted while maintaining sorted order.
+	pub fn seek_binary_search_by<F: FnMut(&Tape::Item) -> Ordering>(
+		&mut self,
+		mut f: F,
+	) -> Result<usize, usize> {
+		self.record_seek();
+		let mut low = 0;
+		let mut high = self.inner.len();
 
-		assert!(
-			!collection.is_cursor_at_end(),
-			"should return false when not at end"
-		);
+		while low < high {
+			let mid = low + (high - low) / 2;
+			match self.inner.get_item(mid).map_or(Ordering::Greater, &mut f) {
+				Ordering::Less => low = mid + 1,
+				Ordering::Greater => high = mid,
+				Ordering::Equal => {
+					self.pos = mid;
+					return Ok(mid);
+				}
+			}
+		}
 
-		collection.pos = collection.inner.len();
-		assert!(
-			collection.is_cursor_at_end(),
-			"should return true when at end"
-		);
+		self.pos = low;
+		Err(low)
 	}
 
-	#[test]
-	fn seek() {
-		fn inner(
-			collection: &mut TestCollection,
-			seek_from: SeekFrom,
-			expected_result: Option<usize>,
-			expected_pos: usize,
-			error_message: &'static str,
-		) {
-			let new_pos = collection.seek(seek_from);
-			assert_eq!(new_pos, expected_result, "{error_message}");
-			assert_eq!(
-				collection.pos, expected_pos,
-				"the seek did not place the cursor at the expected position"
-			);
-		}
+	/// Binary searches the collection with a key extraction function, and moves the cursor to the
+	/// result.
+	///
+	/// The collection is expected to be sorted by the key. See [`Self::seek_binary_search_by()`]
+	/// for the meaning of the return value.
+	///
+	/// # Errors
+	/// If no matching item was found, returns `Err(index)`, where `index` is the position at which
+	/// a matching item could be inserted while maintaining sorted order.
+	pub fn seek_binary_search_by_key<K: Ord, F: FnMut(&Tape::Item) -> K>(
+		&mut self,
+		key: &K,
+		mut f: F,
+	) -> Result<usize, usize> {
+		self.seek_binary_search_by(|item| f(item).cmp(key))
+	}
 
-		let mut collection = self::test_collection();
+	/// Moves the cursor to the partition point of the collection according to `pred`, being the
+	/// index of the first item for which `pred` returns `false`. If `pred` returns `true` for
+	/// every item, the cursor is moved to one index past the last item.
+	///
+	/// The collection is expected to be partitioned, such that `pred` returns `true` for every item
+	/// before the partition point and `false` for every item after it. If it is not, the result is
+	/// unspecified, but the cursor will still be left at a valid position.
+	///
+	/// Returns the cursor's new position.
+	pub fn seek_partition_point<F: FnMut(&Tape::Item) -> bool>(&mut self, mut pred: F) -> usize {
+		self.seek_binary_search_by(|item| {
+			if pred(item) {
+				Ordering::Less
+			} else {
+				Ordering::Greater
+			}
+		})
+		.unwrap_or_else(|index| index)
+	}
+}
 
-		// Seeking to within valid bounds should return the `Some(the new position)` and move the
-		// cursor
-		inner(
-			&mut collection,
-			SeekFrom::Start(3),
-			Some(3),
-			3,
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: PartialEq,
+{
+	/// Moves the cursor backwards to the first item of the run of equal items that the cursor is
+	/// currently within.
+	///
+	/// If the cursor is not pointing at an item, nothing will happen.
+	pub fn seek_to_start_of_run(&mut self) {
+		let Some(current) = self.inner.get_item(self.pos) else {
+			return;
+		};
+
+		let start = (0..self.pos)
+			.rev()
+			.find(|&index| self.inner.get_item(index) != Some(current))
+			.map_or(0, |index| index + 1);
+		self.record_seek();
+		self.pos = start;
+	}
+
+	/// Moves the cursor forwards to the last item of the run of equal items that the cursor is
+	/// currently within.
+	///
+	/// If the cursor is not pointing at an item, nothing will happen.
+	pub fn seek_to_end_of_run(&mut self) {
+		let Some(current) = self.inner.get_item(self.pos) else {
+			return;
+		};
+
+		let collection_len = self.inner.len();
+		let end = (self.pos + 1..collection_len)
+			.find(|&index| self.inner.get_item(index) != Some(current))
+			.unwrap_or(collection_len);
+		self.record_seek();
+		self.pos = end - 1;
+	}
+
+	/// Moves the cursor forwards past the next item equal to `delim`, starting with the item
+	/// pointed at by the cursor. If no such item exists, the cursor is moved to one index past the
+	/// last item.
+	///
+	/// Returns the range of indices the cursor moved past, including the delimiter if one was
+	/// found.
+	pub fn read_until_span(&mut self, delim: &Tape::Item) -> Range<usize> {
+		let collection_len = self.inner.len();
+		let start = self.pos.min(collection_len);
+		self.pos = (start..collection_len)
+			.find(|&index| self.inner.get_item(index) == Some(delim))
+			.map_or(collection_len, |index| index + 1);
+		self.record_reads(self.pos - start);
+		start..self.pos
+	}
+
+	/// Returns whether the items starting at the cursor are equal to `prefix`. An empty `prefix`
+	/// always matches.
+	///
+	/// The cursor is not moved.
+	pub fn starts_with(&self, prefix: &[Tape::Item]) -> bool {
+		prefix
+			.iter()
+			.enumerate()
+			.all(|(offset, expected)| self.inner.get_item(self.pos + offset) == Some(expected))
+	}
+
+	/// If the items starting at the cursor are equal to `prefix`, moves the cursor forwards past
+	/// them. Otherwise, the cursor is not moved.
+	///
+	/// Returns whether `prefix` matched.
+	pub fn consume_prefix(&mut self, prefix: &[Tape::Item]) -> bool {
+		let matched = self.starts_with(prefix);
+		if matched {
+			self.pos += prefix.len();
+			self.record_reads(prefix.len());
+		}
+		matched
+	}
+
+	/// Searches forwards from the cursor for the first item equal to `value`, and moves the cursor
+	/// to it. The item pointed at by the cursor is included in the search.
+	///
+	/// Unlike [`Self::seek_to_prev()`], repeated calls will keep finding the same item, as the
+	/// cursor is left pointing at it. To find each matching item in turn, move past the match (such
+	/// as with [`Self::seek_forward_one()`]) before searching again. Including the item at the
+	/// cursor matches the crate's other forward searches, such as [`Self::seek_to_match()`].
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the matching item, or `None` if no
+	/// item matches. In the latter case, the cursor will not be moved.
+	pub fn seek_to_next(&mut self, value: &Tape::Item) -> Option<usize> {
+		self.record_seek();
+		let found = (self.pos..self.inner.len())
+			.find(|&index| self.inner.get_item(index) == Some(value))?;
+		self.pos = found;
+		Some(found)
+	}
+
+	/// Searches backwards from the item just before the cursor for the first item equal to
+	/// `value`, and moves the cursor to it. The item pointed at by the cursor is not included in
+	/// the search, so repeated calls will find successively earlier items. See
+	/// [`Self::seek_to_next()`] for how this differs from searching forwards.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the matching item, or `None` if no
+	/// item matches. In the latter case, the cursor will not be moved.
+	pub fn seek_to_prev(&mut self, value: &Tape::Item) -> Option<usize> {
+		self.record_seek();
+		let found = self.rposition_before_cursor(|item| item == value)?;
+		self.pos = found;
+		Some(found)
+	}
+
+	/// If the item pointed at by the cursor is equal to `expected`, moves the cursor forwards by
+	/// one index. Otherwise, the cursor is not moved.
+	///
+	/// # Errors
+	/// If the item at the cursor is not equal to `expected`, or the cursor is not pointing at an
+	/// item, returns an [`UnexpectedItem`] holding the cursor's position and a reference to the
+	/// item found there (if any). To hold a clone of the item instead, use
+	/// [`Self::expect_item_cloned()`].
+	pub fn expect_item(
+		&mut self,
+		expected: &Tape::Item,
+	) -> Result<(), UnexpectedItem<&Tape::Item>> {
+		if self
+			.inner
+			.get_item(self.pos)
+			.is_some_and(|found| found == expected)
+		{
+			self.pos += 1;
+			self.record_reads(1);
+			return Ok(());
+		}
+
+		Err(UnexpectedItem {
+			position: self.pos,
+			found: self.inner.get_item(self.pos),
+		})
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: PartialEq + Clone,
+{
+	/// If the item pointed at by the cursor is equal to `expected`, moves the cursor forwards by
+	/// one index. Otherwise, the cursor is not moved.
+	///
+	/// This is the same as [`Self::expect_item()`], except that the returned error holds a clone
+	/// of the item found, rather than borrowing it from the collection.
+	///
+	/// # Errors
+	/// If the item at the cursor is not equal to `expected`, or the cursor is not pointing at an
+	/// item, returns an [`UnexpectedItem`] holding the cursor's position and a clone of the item
+	/// found there (if any).
+	pub fn expect_item_cloned(
+		&mut self,
+		expected: &Tape::Item,
+	) -> Result<(), UnexpectedItem<Tape::Item>> {
+		self.expect_item(expected).map_err(UnexpectedItem::cloned)
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: Ord,
+{
+	/// Binary searches the collection for `target`, and moves the cursor to the result.
+	///
+	/// The collection is expected to be sorted. See [`Self::seek_binary_search_by()`] for the
+	/// meaning of the return value.
+	///
+	/// # Errors
+	/// If no matching item was found, returns `Err(index)`, where `index` is the position at which
+	/// a matching item could be inserted while maintaining sorted order.
+	pub fn seek_binary_search(&mut self, target: &Tape::Item) -> Result<usize, usize> {
+		self.seek_binary_search_by(|item| item.cmp(target))
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Copies items into `buf`, starting with the item pointed at by the cursor, then moves the
+	/// cursor forwards past the copied items.
+	///
+	/// If fewer than `buf.len()` items remain after the cursor, only that many items are copied,
+	/// and the rest of `buf` is left untouched. Returns the number of items copied.
+	pub fn read_items(&mut self, buf: &mut [Tape::Item]) -> usize {
+		let mut copied = 0;
+		for slot in buf {
+			let Some(item) = self.inner.get_item(self.pos) else {
+				break;
+			};
+			slot.clone_from(item);
+			self.pos += 1;
+			copied += 1;
+		}
+		self.record_reads(copied);
+		copied
+	}
+
+	/// Fills `buf` with copies of the items starting at the cursor, then moves the cursor forwards
+	/// past the copied items.
+	///
+	/// # Errors
+	/// If fewer than `buf.len()` items remain after the cursor, returns [`NotEnoughItems`]. In this
+	/// case, `buf` is left untouched, and the cursor will not be moved.
+	pub fn read_exact_items(&mut self, buf: &mut [Tape::Item]) -> Result<(), NotEnoughItems> {
+		let available = self.inner.len().saturating_sub(self.pos);
+		if available < buf.len() {
+			return Err(NotEnoughItems {
+				requested: buf.len(),
+				available,
+			});
+		}
+
+		self.read_items(buf);
+		Ok(())
+	}
+}
+
+impl<Tape: IndexableCollectionMut> CollectionCursor<Tape> {
+	/// Returns a mutable reference to the element pointed at by the cursor.
+	///
+	/// Returns `None` if the cursor is out-of-bounds.
+	pub fn get_item_at_cursor_mut(&mut self) -> Option<&mut Tape::Item> {
+		self.inner.get_item_mut(self.pos)
+	}
+
+	/// Returns a mutable reference to the element at `index`, regardless of the cursor's position.
+	///
+	/// Returns `None` if `index >= self.get_ref().len()`. The cursor is not moved.
+	pub fn get_mut_at(&mut self, index: usize) -> Option<&mut Tape::Item> {
+		self.inner.get_item_mut(index)
+	}
+
+	/// Returns a mutable iterator over the items from the cursor's position up to the end of the
+	/// collection. See [`IterMut`] for how to use it.
+	///
+	/// The cursor is not moved.
+	pub fn iter_remaining_mut(&mut self) -> IterMut<'_, Tape> {
+		let collection_len = self.inner.len();
+		IterMut::new(&mut self.inner, self.pos, collection_len)
+	}
+
+	/// Returns a mutable reference to the first element of the collection, regardless of the
+	/// cursor's position.
+	///
+	/// Returns `None` if the collection is empty. The cursor is not moved.
+	pub fn first_mut(&mut self) -> Option<&mut Tape::Item> {
+		self.inner.get_item_mut(0)
+	}
+
+	/// Returns a mutable reference to the last element of the collection, regardless of the
+	/// cursor's position.
+	///
+	/// Returns `None` if the collection is empty. The cursor is not moved.
+	pub fn last_mut(&mut self) -> Option<&mut Tape::Item> {
+		let last_index = self.inner.len().checked_sub(1)?;
+		self.inner.get_item_mut(last_index)
+	}
+
+	/// Sets the slot at the cursor to `item`.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if `self.position() >= self.get_ref().len()`.
+	pub fn set_item_at_cursor(&mut self, item: Tape::Item) {
+		self.inner.set_item(self.pos, item);
+		self.record_writes(1);
+	}
+}
+
+impl<Tape: IndexableCollectionMut> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Sets up to `n` slots, starting at the cursor, to clones of `value`. Slots past the end of the
+	/// collection are not written to, as the collection is never resized.
+	///
+	/// Returns the number of slots written to. The cursor is not moved.
+	pub fn fill_at_cursor(&mut self, n: usize, value: Tape::Item) -> usize {
+		let collection_len = self.inner.len();
+		let start = self.pos.min(collection_len);
+		let end = start.saturating_add(n).min(collection_len);
+
+		for index in start..end {
+			self.inner.set_item(index, value.clone());
+		}
+
+		self.record_writes(end - start);
+		end - start
+	}
+
+	/// Sets the slots starting at the cursor to clones of the items in `src`, in order. Slots past
+	/// the end of the collection are not written to, as the collection is never resized.
+	///
+	/// Returns the number of slots written to. The cursor is not moved.
+	pub fn set_items_from_slice(&mut self, src: &[Tape::Item]) -> usize {
+		let start = self.pos.min(self.inner.len());
+		let written = src.len().min(self.inner.len() - start);
+
+		for (index, item) in (start..).zip(&src[..written]) {
+			self.inner.set_item(index, item.clone());
+		}
+
+		self.record_writes(written);
+		written
+	}
+
+	/// Sets the slots starting at the cursor to clones of the items in `src`, in order, then moves
+	/// the cursor forwards past the written slots. Slots past the end of the collection are not
+	/// written to, as the collection is never resized.
+	///
+	/// Returns the number of slots written to. This mirrors the behavior of writing to a
+	/// `std::io::Cursor` over a fixed-size buffer.
+	pub fn write_items(&mut self, src: &[Tape::Item]) -> usize {
+		let written = self.set_items_from_slice(src);
+		self.pos += written;
+		written
+	}
+
+	/// Sets up to `count` slots, starting at the cursor, to clones of the items in `pattern`,
+	/// repeating `pattern` as many times as needed. Slots past the end of the collection are not
+	/// written to, as the collection is never resized.
+	///
+	/// Returns the number of slots written to. The cursor is not moved.
+	///
+	/// # Panics
+	/// Panics if `pattern` is empty.
+	pub fn overwrite_cycle(&mut self, pattern: &[Tape::Item], count: usize) -> usize {
+		assert!(!pattern.is_empty(), "pattern must not be empty");
+
+		let collection_len = self.inner.len();
+		let start = self.pos.min(collection_len);
+		let end = start.saturating_add(count).min(collection_len);
+
+		for (index, item) in (start..end).zip(pattern.iter().cycle()) {
+			self.inner.set_item(index, item.clone());
+		}
+
+		self.record_writes(end - start);
+		end - start
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
+	/// Removes all elements within the inner collection, and returns the cursor to the index `0`.
+	pub fn clear(&mut self) {
+		let collection_len = self.inner.len();
+		self.inner.clear();
+		self.pos = 0;
+		self.shift_saved_positions_for_remove(0, collection_len);
+	}
+
+	/// Inserts `item` at the cursor, shifting the following elements to the right by one index.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if `self.position() > self.get_ref().len()`.
+	pub fn insert_item_at_cursor(&mut self, item: Tape::Item) {
+		self.inner.insert_item(self.pos, item);
+		self.shift_saved_positions_for_insert(self.pos, 1);
+	}
+
+	/// If `self.position() == self.get_ref().len()`, then insert `item` at the cursor. Otherwise,
+	/// set the slot at the cursor to `item`.
+	///
+	/// This is equivalent to [`Self::set_item_at_cursor`], except when
+	/// `self.position() == self.get_ref().len()`, then it's equivalent to
+	/// [`Self::insert_item_at_cursor`].
+	///
+	/// # Panics
+	/// Panics if the set/insert operation panics. The circumstances for a panic are defined by the
+	/// inner collection, but will usually occur if `self.position() > self.get_ref().len()`.
+	pub fn set_or_insert_item_at_cursor(&mut self, item: Tape::Item) {
+		if self.is_cursor_at_end() {
+			self.insert_item_at_cursor(item);
+		} else {
+			self.set_item_at_cursor(item);
+		}
+	}
+
+	/// Removes and returns the item at the cursor.
+	///
+	/// Returns `None` if `self.position() >= self.get_ref().len()`, or if the remove operation
+	/// would normally panic.
+	pub fn remove_item_at_cursor(&mut self) -> Option<Tape::Item> {
+		// Note: We don't have to worry about moving the cursor. If the cursor is on the last item,
+		// removing will put it one index past the end, which is still within the valid area for the
+		// cursor to be. Meanwhile, if it's past the end, no item will be removed.
+		let item = self.inner.remove_item(self.pos)?;
+		self.shift_saved_positions_for_remove(self.pos, 1);
+		Some(item)
+	}
+
+	/// Removes and returns the item at the cursor, but only if `pred` returns `true` for it.
+	///
+	/// Returns `None` without modifying the collection if `self.position() >= self.get_ref().len()`,
+	/// or if `pred` returns `false`.
+	pub fn remove_at_cursor_if<F: FnOnce(&Tape::Item) -> bool>(
+		&mut self,
+		pred: F,
+	) -> Option<Tape::Item> {
+		if self.get_item_at_cursor().is_some_and(pred) {
+			self.remove_item_at_cursor()
+		} else {
+			None
+		}
+	}
+
+	/// Swaps the item just before the cursor with the item at the cursor, then moves the cursor
+	/// forward by one index, in the same way as the "transpose" command of many text editors.
+	///
+	/// Returns `true` if the items were swapped. If the cursor is at index `0`, or is not pointing
+	/// at an item, returns `false` without modifying the collection or moving the cursor.
+	pub fn transpose_at_cursor(&mut self) -> bool {
+		if self.pos == 0 || self.pos >= self.inner.len() {
+			return false;
+		}
+
+		let Some(before) = self.inner.remove_item(self.pos - 1) else {
+			return false;
+		};
+		self.inner.insert_item(self.pos, before);
+		let pos = self.pos;
+		self.trace_edit(format_args!(
+			"transposed the items at indices {} and {pos}",
+			pos - 1
+		));
+		self.adjust_saved_positions(|saved| Self::position_after_move(saved, pos - 1, pos));
+		self.pos += 1;
+		#[cfg(feature = "alloc")]
+		self.notify_edit(CursorEventKind::Reorder {
+			start: pos - 1,
+			end: pos + 1,
+		});
+		true
+	}
+
+	/// Moves the item at index `from` to index `to`, shifting the items in between by one index to
+	/// fill the gap.
+	///
+	/// `to` is the index the item will have after the move. Note that when `from < to`, this means
+	/// the item will be placed *after* the item which was at `to` before the move, as removing the
+	/// item first shifts the following items back by one index.
+	///
+	/// The cursor is adjusted so that it keeps pointing at the same item as it did before the move
+	/// (or at one index past the last item, if it was there before).
+	///
+	/// Returns `true` if the item was moved. If either index is `>= self.get_ref().len()`, returns
+	/// `false` without modifying the collection or moving the cursor.
+	pub fn move_item(&mut self, from: usize, to: usize) -> bool {
+		let collection_len = self.inner.len();
+		if from >= collection_len || to >= collection_len {
+			return false;
+		}
+
+		let Some(item) = self.inner.remove_item(from) else {
+			return false;
+		};
+		self.inner.insert_item(to, item);
+
+		self.pos = Self::position_after_move(self.pos, from, to);
+		self.adjust_saved_positions(|saved| Self::position_after_move(saved, from, to));
+		#[cfg(feature = "alloc")]
+		self.notify_edit(CursorEventKind::Reorder {
+			start: from.min(to),
+			end: from.max(to) + 1,
+		});
+		self.trace_edit(format_args!("moved the item at index {from} to index {to}"));
+		true
+	}
+
+	/// Computes where `pos` ends up after the item at index `from` is moved to index `to`, such that
+	/// it keeps pointing at the same item.
+	fn position_after_move(pos: usize, from: usize, to: usize) -> usize {
+		if pos == from {
+			return to;
+		}
+
+		let mut pos = pos;
+		if pos > from {
+			pos -= 1;
+		}
+		if pos >= to {
+			pos += 1;
+		}
+		pos
+	}
+
+	/// Rotates the inner collection so that the item at the cursor becomes the first item, and
+	/// moves the cursor to index `0`. The items before the cursor are moved to the back of the
+	/// collection, keeping their relative order.
+	///
+	/// If the cursor is not pointing at an item, the collection is left as-is, and the cursor is
+	/// moved to index `0`.
+	///
+	/// # Performance
+	/// The rotation is done by [`IndexableCollectionResizable::rotate_items_left()`]. For
+	/// contiguous collections (such as `Vec`), this uses [`slice::rotate_left()`], taking `O(n)`
+	/// time. Other collections move items one at a time from whichever end holds fewer of them,
+	/// which also takes `O(n)` time for collections with cheap access to both ends (such as
+	/// `VecDeque`).
+	pub fn rotate_cursor_to_front(&mut self) {
+		let collection_len = self.inner.len();
+
+		if self.pos < collection_len {
+			self.inner.rotate_items_left(0..collection_len, self.pos);
+			self.finish_rotation_to_front();
+		} else {
+			self.pos = 0;
+		}
+	}
+
+	/// Removes every item from the cursor to the end of the inner collection, and returns them
+	/// in order within a new collection of type `C`. Afterwards, the cursor will be one index past
+	/// the last item.
+	///
+	/// Items are removed one at a time from the cursor's position, so this may be slow for
+	/// collections where removing from the middle is expensive.
+	pub fn collect_remaining<C>(&mut self) -> C
+	where
+		C: IndexableCollectionResizable<Item = Tape::Item> + Default,
+	{
+		let mut collected = C::default();
+		while let Some(item) = self.inner.remove_item(self.pos) {
+			collected.insert_item(collected.len(), item);
+		}
+		self.shift_saved_positions_for_remove(self.pos, collected.len());
+
+		self.clamp_to_end();
+		collected
+	}
+
+	/// Returns a mutable reference to the element pointed at by the cursor. If the cursor is one
+	/// index past the last item, `f()` is first inserted at the cursor, and a reference to it is
+	/// returned instead.
+	///
+	/// # Panics
+	/// Panics if `self.position() > self.get_ref().len()`, or if the inner collection does not
+	/// contain an item at the cursor after inserting one.
+	pub fn item_at_cursor_or_insert_with<F: FnOnce() -> Tape::Item>(
+		&mut self,
+		f: F,
+	) -> &mut Tape::Item {
+		if let Err(error) = self.check_invariant() {
+			panic!("{error}");
+		}
+
+		if self.is_cursor_at_end() {
+			self.inner.insert_item(self.pos, f());
+			self.shift_saved_positions_for_insert(self.pos, 1);
+		}
+
+		self.inner
+			.get_item_mut(self.pos)
+			.expect("the collection should contain an item at the cursor")
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape>
+where
+	Tape::Item: Ord,
+{
+	/// Merges the items of `other` into the inner collection, keeping the result sorted.
+	///
+	/// Both the inner collection and `other` must already be sorted in ascending order. If either
+	/// isn't, no items will be lost, but the order of the resulting collection is unspecified.
+	/// Items which compare as equal keep their relative order, with the inner collection's items
+	/// placed before `other`'s items.
+	///
+	/// The cursor is kept at the same index. The checkpoints and marks are instead adjusted so that
+	/// they keep pointing at the same items, with those one index past the last item staying there.
+	///
+	/// # Panics
+	/// Panics if an insert operation panics. Collections with a fixed capacity (such as `ArrayVec`)
+	/// will panic if that capacity can't hold the items of both collections.
+	///
+	/// # Performance
+	/// The items of `other` are taken from its back, and inserted into the inner collection in
+	/// place, walking backwards from its end. This takes `O(n + m)` comparisons, where `n` and `m`
+	/// are the lengths of the two collections, plus the cost of each insert. For contiguous
+	/// collections (such as `Vec`), each insert shifts the items after it, making this
+	/// `O(n * m)` in the worst case.
+	pub fn merge_sorted(&mut self, mut other: Tape) {
+		// Every item at or after `boundary` is larger than every item of `other` that is yet to be
+		// inserted. Each item of `other` is inserted at the boundary, in front of the larger items
+		// inserted before it, and after any of our items which compare as equal to it.
+		let mut boundary = self.inner.len();
+		while let Some(last_index) = other.len().checked_sub(1) {
+			let Some(item) = other.remove_item(last_index) else {
+				break;
+			};
+
+			while let Some(ours) = boundary.checked_sub(1).and_then(|i| self.inner.get_item(i)) {
+				if *ours <= item {
+					break;
+				}
+				boundary -= 1;
+			}
+
+			self.inner.insert_item(boundary, item);
+			self.shift_saved_positions_for_insert(boundary, 1);
+		}
+	}
+}
+
+impl<Tape: ContiguousCollection + IndexableCollectionResizable> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Inserts a clone of every item in `src` at the cursor, keeping their order and shifting the
+	/// following elements to the right by `src.len()` indices. The cursor is left pointing at the
+	/// first inserted item (or is not moved, if `src` is empty).
+	///
+	/// Rather than inserting each item at the cursor in turn, the items are appended to the end of
+	/// the collection and then rotated into place, so that the following elements are only shifted
+	/// once. This requires `Tape::Item: Clone`, as the items are copied out of `src`.
+	///
+	/// # Panics
+	/// Panics if `self.position() > self.get_ref().len()`, or if an insert operation panics.
+	pub fn insert_slice_at_cursor(&mut self, src: &[Tape::Item]) {
+		assert!(
+			self.pos <= self.inner.len(),
+			"cursor position should be within the bounds of the collection"
+		);
+
+		for item in src {
+			self.inner.insert_item(self.inner.len(), item.clone());
+		}
+		self.inner.as_mut_slice()[self.pos..].rotate_right(src.len());
+		self.shift_saved_positions_for_insert(self.pos, src.len());
+	}
+}
+
+impl<Tape: ContiguousCollection> CollectionCursor<Tape> {
+	/// Splits the inner collection at the cursor, returning a slice of the items before the cursor,
+	/// and a slice of the items from the cursor onwards.
+	pub fn split_at_cursor_ref(&self) -> (&[Tape::Item], &[Tape::Item]) {
+		let items = self.inner.as_slice();
+		items.split_at(self.pos.min(items.len()))
+	}
+
+	/// Splits the inner collection at the cursor, returning a mutable slice of the items before
+	/// the cursor, and a mutable slice of the items from the cursor onwards.
+	///
+	/// This allows both halves to be modified at the same time - for example, copying items which
+	/// have already been processed into the items which have yet to be processed.
+	pub fn split_at_cursor(&mut self) -> (&mut [Tape::Item], &mut [Tape::Item]) {
+		let items = self.inner.as_mut_slice();
+		let mid = self.pos.min(items.len());
+		items.split_at_mut(mid)
+	}
+
+	/// Returns a reference to the element pointed at by the cursor, along with a slice of the items
+	/// after it.
+	///
+	/// Returns `None` if `self.position() >= self.get_ref().len()`.
+	pub fn split_first_from_cursor(&self) -> Option<(&Tape::Item, &[Tape::Item])> {
+		self.inner.as_slice().get(self.pos..)?.split_first()
+	}
+
+	/// Returns a slice of the items from the cursor onwards.
+	///
+	/// If the cursor is one index past the last item, the slice will be empty.
+	pub fn remaining_slice(&self) -> &[Tape::Item] {
+		let items = self.inner.as_slice();
+		&items[self.pos.min(items.len())..]
+	}
+
+	/// Returns a mutable slice of the items from the cursor onwards.
+	///
+	/// If the cursor is one index past the last item, the slice will be empty.
+	pub fn remaining_slice_mut(&mut self) -> &mut [Tape::Item] {
+		let items = self.inner.as_mut_slice();
+		let start = self.pos.min(items.len());
+		&mut items[start..]
+	}
+
+	/// Returns a slice of the items before the cursor.
+	pub fn consumed_slice(&self) -> &[Tape::Item] {
+		let items = self.inner.as_slice();
+		&items[..self.pos.min(items.len())]
+	}
+
+	/// Returns a mutable slice of the items before the cursor.
+	pub fn consumed_slice_mut(&mut self) -> &mut [Tape::Item] {
+		let items = self.inner.as_mut_slice();
+		let end = self.pos.min(items.len());
+		&mut items[..end]
+	}
+
+	/// Returns a new cursor over the items in `lo..hi`, borrowed mutably from the inner collection.
+	/// The new cursor's position starts at `0`, relative to the start of that range.
+	///
+	/// This cursor is not moved.
+	///
+	/// Returns `None` if `lo > hi` or `hi > self.get_ref().len()`.
+	pub fn sub_cursor(
+		&mut self,
+		lo: usize,
+		hi: usize,
+	) -> Option<CollectionCursor<&mut [Tape::Item]>> {
+		self.inner
+			.as_mut_slice()
+			.get_mut(lo..hi)
+			.map(CollectionCursor::new)
+	}
+}
+
+impl<Tape: DequeCollection> CollectionCursor<Tape> {
+	/// Inserts `item` at the front of the inner collection.
+	///
+	/// The cursor is moved forward by one index, so that it keeps pointing at the same item as it
+	/// did before the push (or at one index past the last item, if it was there before).
+	pub fn push_front(&mut self, item: Tape::Item) {
+		self.inner.push_front(item);
+		self.pos += 1;
+		self.shift_saved_positions_for_insert(0, 1);
+	}
+
+	/// Inserts `item` at the back of the inner collection.
+	///
+	/// The cursor is not moved. Note that this means if the cursor was one index past the last
+	/// item, it will now point at `item`.
+	pub fn push_back(&mut self, item: Tape::Item) {
+		let collection_len = self.inner.len();
+		self.inner.push_back(item);
+		self.shift_saved_positions_for_insert(collection_len, 1);
+	}
+
+	/// Removes and returns the item at the front of the inner collection, or `None` if the
+	/// collection is empty.
+	///
+	/// If the cursor is after the front item, it is moved backward by one index, so that it keeps
+	/// pointing at the same item as it did before the pop. If the cursor was pointing at the front
+	/// item, it will now point at the item which followed it.
+	pub fn pop_front(&mut self) -> Option<Tape::Item> {
+		let item = self.inner.pop_front()?;
+		self.pos = self.pos.saturating_sub(1);
+		self.shift_saved_positions_for_remove(0, 1);
+		Some(item)
+	}
+
+	/// Removes and returns the item at the back of the inner collection, or `None` if the
+	/// collection is empty.
+	///
+	/// If the cursor was pointing at the back item (or one index past it), it is clamped to one
+	/// index past the new last item. Otherwise, the cursor is not moved.
+	pub fn pop_back(&mut self) -> Option<Tape::Item> {
+		let item = self.inner.pop_back()?;
+		self.clamp_to_end();
+		self.shift_saved_positions_for_remove(self.inner.len(), 1);
+		Some(item)
+	}
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeekFrom {
+	/// Moves the cursor to the provided index.
+	///
+	/// # Examples
+	/// * `SeekFrom::Start(0)` will move the cursor to the first item
+	/// * `SeekFrom::Start(5)` will move the cursor to the sixth item
+	Start(usize),
+	/// Moves the cursor to the cassette's length (as provided by [`IndexableCollection::len`]) plus
+	/// the provided number of indices.
+	///
+	/// It is an error to seek before the first index, or more than one index past the last item,
+	/// unless the cursor's [`SeekPolicy`] says otherwise.
+	///
+	/// # Examples
+	/// * `SeekFrom::End(-1)` will move the cursor to the last item, if one exists
+	/// * `SeekFrom::End(0)` will move the cursor to the index just after the last item
+	End(isize),
+	/// Moves the cursor to the current position (as provided by [`CollectionCursor::position`])
+	/// plus the provided number of indices.
+	///
+	/// It is an error to seek before the first index, or more than one index past the last item,
+	/// unless the cursor's [`SeekPolicy`] says otherwise.
+	///
+	/// # Examples
+	/// * `SeekFrom::Current(-2)` will move the cursor back two indices
+	/// * `SeekFrom::Current(0)` won't move anywhere
+	/// * `SeekFrom::Current(5)` will move the cursor forward five indices
+	Current(isize),
+}
+
+/// How a [`CollectionCursor`] handles seeks which would move it before `0`, or more than one index
+/// past the last item.
+///
+/// This affects [`CollectionCursor::seek()`], and the convenience methods built upon it.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SeekPolicy {
+	/// Refuses to move the cursor, and returns an error.
+	#[default]
+	Strict,
+	/// Moves the cursor as far as it can towards the target, stopping at the beginning of the
+	/// collection or one index past the last item.
+	Clamp,
+	/// Treats the collection as circular, wrapping around to the other side of the collection.
+	Wrap,
+}
+
+/// How [`CollectionCursor::edit_with()`] clamps the cursor after the collection is edited.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClampPolicy {
+	/// If the cursor is more than one index past the last item, moves it to one index past the
+	/// last item, as with [`CollectionCursor::clamp_to_end()`].
+	#[default]
+	ToEnd,
+	/// If the cursor is after the last item (including one index past it), moves it to the last
+	/// item, or `0` if no items exist, as with [`CollectionCursor::clamp_to_last_item()`].
+	ToLastItem,
+}
+
+/// Where a forward search through the collection begins.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SearchOrigin {
+	/// Searches from the first item in the collection.
+	Start,
+	/// Searches from the item pointed at by the cursor (inclusive).
+	Cursor,
+}
+
+#[allow(
+	clippy::len_without_is_empty,
+	reason = "While is_empty would normally be useful, we don't have a use for it here"
+)]
+pub trait IndexableCollection {
+	/// The type of item this container contains.
+	type Item;
+
+	/// Gets the number of items this container currently contains.
+	fn len(&self) -> usize;
+	/// Gets a reference to the item at index `index`. Returns `None` if no item exists at `index`.
+	fn get_item(&self, index: usize) -> Option<&Self::Item>;
+}
+
+pub trait IndexableCollectionMut: IndexableCollection {
+	/// Gets a mutable reference to the item at index `index`. Returns `None` if no item exists at
+	/// `index`.
+	fn get_item_mut(&mut self, index: usize) -> Option<&mut Self::Item>;
+	/// Sets an item at a specific index.
+	///
+	/// This is allowed (and generally expected) to panic if `index >= self.len()`. However, if it
+	/// doesn't, then ensure you are following the "rule of least surprise" - whether through
+	/// documentation or otherwise.
+	fn set_item(&mut self, index: usize, element: Self::Item);
+}
+
+pub trait IndexableCollectionResizable: IndexableCollectionMut {
+	/// Inserts an item at a specific index, moving the item at the index and all items after it
+	/// one index forward.
+	///
+	/// This is allowed (and generally expected) to panic if `index > self.len()`. However, if it
+	/// doesn't, then ensure you are following the "rule of least surprise" - whether through
+	/// documentation or otherwise.
+	fn insert_item(&mut self, index: usize, element: Self::Item);
+	/// Removes the item at index `index` from the container, and returns the item, or `None` if no
+	/// item exists at index `index`.
+	///
+	/// You must ensure that removing at an index past the end of the collection does not panic. If
+	/// the normal `remove()` method of a collection would panic given an invalid index, your
+	/// implementation must check and return `None` in those instances.
+	fn remove_item(&mut self, index: usize) -> Option<Self::Item>;
+	/// Clears the container's contents.
+	fn clear(&mut self);
+	/// Returns how many more items can be inserted before the container's capacity would be
+	/// exceeded, or `None` if the container grows as needed.
+	///
+	/// The default implementation returns `None`. Collections with a fixed capacity (such as
+	/// `ArrayVec`) should override this, so that callers can avoid the panic of inserting into a
+	/// full collection.
+	fn remaining_capacity(&self) -> Option<usize> {
+		None
+	}
+	/// Rotates the items within `range` to the left by `mid` indices, so that the item at index
+	/// `range.start + mid` becomes the first item of the range, and the items before it are moved
+	/// to the end of the range.
+	///
+	/// The default implementation moves the items one at a time, using [`Self::remove_item()`] and
+	/// [`Self::insert_item()`], from whichever side of `mid` holds fewer items. Collections which
+	/// store their items contiguously should override this to use [`slice::rotate_left()`].
+	///
+	/// This is allowed to panic if `range` is out of bounds, or if `mid > range.len()`.
+	fn rotate_items_left(&mut self, range: Range<usize>, mid: usize) {
+		let range_len = range.end - range.start;
+		if mid <= range_len - mid {
+			for _ in 0..mid {
+				if let Some(item) = self.remove_item(range.start) {
+					self.insert_item(range.end - 1, item);
+				}
+			}
+		} else {
+			for _ in mid..range_len {
+				if let Some(item) = self.remove_item(range.end - 1) {
+					self.insert_item(range.start, item);
+				}
+			}
+		}
+	}
+}
+
+/// A collection which stores its items contiguously in memory, allowing them to be viewed as a
+/// slice.
+pub trait ContiguousCollection: IndexableCollection {
+	/// Gets a slice containing every item in the container, in order.
+	fn as_slice(&self) -> &[Self::Item];
+	/// Gets a mutable slice containing every item in the container, in order.
+	fn as_mut_slice(&mut self) -> &mut [Self::Item];
+}
+
+/// A collection which can efficiently add and remove items at both of its ends.
+///
+/// Every method has a default implementation built on [`IndexableCollectionResizable`], so
+/// implementing this trait only requires overriding the methods which the collection can perform
+/// faster than an arbitrary-index insert or remove.
+pub trait DequeCollection: IndexableCollectionResizable {
+	/// Inserts an item at the front of the container.
+	fn push_front(&mut self, element: Self::Item) {
+		self.insert_item(0, element);
+	}
+	/// Inserts an item at the back of the container.
+	fn push_back(&mut self, element: Self::Item) {
+		self.insert_item(self.len(), element);
+	}
+	/// Removes the item at the front of the container and returns it, or `None` if the container is
+	/// empty.
+	fn pop_front(&mut self) -> Option<Self::Item> {
+		self.remove_item(0)
+	}
+	/// Removes the item at the back of the container and returns it, or `None` if the container is
+	/// empty.
+	fn pop_back(&mut self) -> Option<Self::Item> {
+		self.remove_item(self.len().checked_sub(1)?)
+	}
+}
+
+#[cfg(test)]
+mod collection_cursor_tests {
+	extern crate alloc;
+
+	use super::*;
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	type TestVec = Vec<i32>;
+	type TestCollection = CollectionCursor<TestVec>;
+
+	fn test_vec() -> TestVec {
+		let res = Vec::from([0, 1, 2, 3, 4, 5, 9, 8, 7, 6]);
+
+		// Ensure that the length is a known value.
+		// IF YOU CHANGE THIS, ENSURE TESTS ARE CHANGED TO MATCH.
+		assert_eq!(res.len(), 10);
+
+		res
+	}
+
+	fn test_collection() -> TestCollection {
+		let res = CollectionCursor {
+			pos: Default::default(),
+			..CollectionCursor::new(self::test_vec())
+		};
+
+		// Ensure that the cursor position is a known value.
+		// IF YOU CHANGE THIS, ENSURE TESTS ARE CHANGED TO MATCH.
+		assert_eq!(res.pos, Default::default());
+
+		res
+	}
+
+	#[test]
+	fn new() {
+		let new_collection = CollectionCursor::new(self::test_vec());
+		let test_collection = self::test_collection();
+
+		assert_eq!(new_collection, test_collection);
+	}
+
+	#[test]
+	fn position() {
+		let mut collection = self::test_collection();
+		assert_eq!(collection.position(), 0);
+
+		collection.pos = 5;
+		assert_eq!(collection.position(), 5);
+
+		collection.pos = usize::MAX;
+		assert_eq!(collection.position(), usize::MAX);
+	}
+
+	#[test]
+	fn get_ref() {
+		let collection = self::test_collection();
+		assert_eq!(collection.get_ref(), &self::test_vec());
+	}
+
+	#[test]
+	fn get_mut() {
+		let mut collection = self::test_collection();
+		assert_eq!(collection.get_mut(), &mut self::test_vec());
+	}
+
+	#[test]
+	fn into_inner() {
+		let collection = self::test_collection();
+		assert_eq!(collection.into_inner(), self::test_vec());
+	}
+
+	#[test]
+	fn into_parts() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		assert_eq!(collection.into_parts(), (self::test_vec(), 5));
+	}
+
+	#[test]
+	fn from_parts() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		let (inner, pos) = collection.clone().into_parts();
+		assert_eq!(
+			CollectionCursor::from_parts(inner, pos),
+			Ok(collection),
+			"should rebuild the same cursor"
+		);
+
+		let len = self::test_vec().len();
+		assert!(
+			CollectionCursor::from_parts(self::test_vec(), len).is_ok(),
+			"should accept a position one past the last item"
+		);
+		assert_eq!(
+			CollectionCursor::from_parts(self::test_vec(), len + 1),
+			Err((self::test_vec(), len + 1)),
+			"should hand back the parts when the position is out-of-bounds"
+		);
+	}
+
+	#[test]
+	fn swap_positions_with() {
+		let mut collection = self::test_collection();
+		let mut other_collection = CollectionCursor::new(Vec::from([5, 6, 7]));
+
+		collection.pos = 8;
+		other_collection.pos = 2;
+		collection.swap_positions_with(&mut other_collection);
+
+		assert_eq!(collection.pos, 2, "should take the other cursor's position");
+		assert_eq!(
+			other_collection.pos, 8,
+			"should give its position to the other cursor"
+		);
+		assert_eq!(
+			collection.inner,
+			self::test_vec(),
+			"shouldn't modify the inner collection"
+		);
+		assert_eq!(
+			other_collection.inner,
+			[5, 6, 7],
+			"shouldn't modify the other inner collection"
+		);
+	}
+
+	#[test]
+	fn is_cursor_at_end() {
+		let mut collection = self::test_collection();
+
+		assert!(
+			!collection.is_cursor_at_end(),
+			"should return false when not at end"
+		);
+
+		collection.pos = collection.inner.len();
+		assert!(
+			collection.is_cursor_at_end(),
+			"should return true when at end"
+		);
+	}
+
+	#[test]
+	fn check_invariant() {
+		let mut collection = self::test_collection();
+		assert_eq!(collection.check_invariant(), Ok(()));
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.check_invariant(),
+			Ok(()),
+			"should accept the cursor being one past the last item"
+		);
+
+		collection.pos = usize::MAX;
+		let error = collection
+			.check_invariant()
+			.expect_err("should reject the cursor being past the end");
+		assert_eq!(error.position(), usize::MAX);
+		assert_eq!(error.collection_len(), collection.inner.len());
+	}
+
+	#[test]
+	fn debug_assert_invariant() {
+		let mut collection = self::test_collection();
+		collection.debug_assert_invariant();
+
+		collection.pos = collection.inner.len();
+		collection.debug_assert_invariant();
+	}
+
+	#[test]
+	#[cfg(debug_assertions)]
+	#[should_panic = "cursor position 11 is past the end of the collection (length 10)"]
+	fn debug_assert_invariant_panic() {
+		let mut collection = self::test_collection();
+		collection.pos = 11;
+		collection.debug_assert_invariant();
+	}
+
+	#[test]
+	fn seek() {
+		fn inner(
+			collection: &mut TestCollection,
+			seek_from: SeekFrom,
+			expected_result: Option<usize>,
+			expected_pos: usize,
+			error_message: &'static str,
+		) {
+			let new_pos = collection.seek(seek_from).ok();
+			assert_eq!(new_pos, expected_result, "{error_message}");
+			assert_eq!(
+				collection.pos, expected_pos,
+				"the seek did not place the cursor at the expected position"
+			);
+		}
+
+		let mut collection = self::test_collection();
+
+		// Seeking to within valid bounds should return the `Some(the new position)` and move the
+		// cursor
+		inner(
+			&mut collection,
+			SeekFrom::Start(3),
+			Some(3),
+			3,
 			"`Start(x)` should move the cursor within the bounds of the collection",
 		);
-		inner(
-			&mut collection,
+		inner(
+			&mut collection,
+			SeekFrom::Start(0),
+			Some(0),
+			0,
+			"`Start(0)` should move the cursor to the start of the collection",
+		);
+
+		inner(
+			&mut collection,
+			SeekFrom::Current(0),
+			Some(0),
+			0,
+			"`Current(0) shouldn't move the cursor",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::Current(7),
+			Some(7),
+			7,
+			"`Current(x)` should move the cursor forward within the bounds of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::Current(-2),
+			Some(5),
+			5,
+			"`Current(-x) should move the cursor backwards within the bounds of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::Current(-5),
+			Some(0),
+			0,
+			"`Current(-current_pos) should move the cursor to the start of the collection",
+		);
+
+		// TODO: De-hardcode the expected results
+		inner(
+			&mut collection,
+			SeekFrom::End(0),
+			Some(10),
+			10,
+			"`End(0)` should move the cursor to one past the end of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::End(-1),
+			Some(9),
+			9,
+			"`End(-1)` should move the cursor to the end of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::End(-5),
+			Some(5),
+			5,
+			"`End(-x)` should move the cursor within the bounds of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::End(-10),
+			Some(0),
+			0,
+			"`End(-len)` should move the cursor to the start of the collection",
+		);
+
+		// Seek to a known position. We reuse the testing function to ensure we're actually there,
+		// just in case the test data has been messed with improperly.
+		inner(
+			&mut collection,
+			SeekFrom::Start(7),
+			Some(7),
+			7,
+			"this shouldn't fail",
+		);
+
+		// Seeking outside valid bounds should return `None` and *not* move the cursor
+		inner(
+			&mut collection,
+			SeekFrom::Start(usize::MAX),
+			None,
+			7,
+			"`Start(x)` shouldn't move if doing so would put it past one index past the end of the collection",
+		);
+
+		inner(
+			&mut collection,
+			SeekFrom::Current(-isize::MAX),
+			None,
+			7,
+			"`Current(-X)` shouldn't move if doing so would put it past the start of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::Current(isize::MAX),
+			None,
+			7,
+			"`Current(x)` shouldn't move if doing so would put it past the end of the collection",
+		);
+
+		inner(
+			&mut collection,
+			SeekFrom::End(1),
+			None,
+			7,
+			"`End(1)` shouldn't move if doing so would put it past the end of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::End(-isize::MAX),
+			None,
+			7,
+			"`End(-x)` shouldn't move if doing so would put it past the start of the collection",
+		);
+		inner(
+			&mut collection,
+			SeekFrom::End(isize::MAX),
+			None,
+			7,
+			"`End(x)` shouldn't move if doing so would put it past the end of the collection",
+		);
+	}
+
+	macro_rules! __clamp_to {
+		($method:tt, $first_test_expected_pos:expr, $first_test_error_message:expr) => {
+			let mut collection = self::test_collection();
+
+			collection.pos = usize::MAX;
+			collection.$method();
+			assert_eq!(
+				collection.pos, $first_test_expected_pos,
+				$first_test_error_message
+			);
+
+			collection.pos = 2;
+			collection.$method();
+			assert_eq!(
+				collection.pos, 2,
+				"shouldn't move the cursor when already within the bounds of the collection"
+			);
+
+			collection = CollectionCursor::new(Vec::from([]));
+
+			collection.pos = 0;
+			collection.$method();
+			assert_eq!(
+				collection.pos, 0,
+				"should keep the cursor at index `0` when given an empty collection"
+			);
+
+			collection.pos = usize::MAX;
+			collection.$method();
+			assert_eq!(
+				collection.pos, 0,
+				"should keep the cursor at index `0` when given an empty collection"
+			);
+		};
+	}
+
+	#[test]
+	fn clamp_to_last_item() {
+		let collection_len = self::test_collection().inner.len();
+
+		__clamp_to!(
+			clamp_to_last_item,
+			collection_len - 1,
+			"should move the cursor to the last item of the collection"
+		);
+	}
+
+	#[test]
+	fn clamp_to_end() {
+		let collection_len = self::test_collection().inner.len();
+
+		__clamp_to!(
+			clamp_to_end,
+			collection_len,
+			"should move the cursor to the one index past the last item of the collection"
+		);
+	}
+
+	#[test]
+	fn seek_backward_one() {
+		fn inner(
+			collection: &mut TestCollection,
+			should_succeed: bool,
+			expected_new_pos: usize,
+			error_message: &'static str,
+		) {
+			let seek_success = collection.seek_backward_one();
+			assert_eq!(seek_success, should_succeed, "{error_message}");
+			assert_eq!(
+				collection.pos, expected_new_pos,
+				"cursor was not moved to the expected position"
+			);
+		}
+
+		let mut collection = self::test_collection();
+
+		inner(
+			&mut collection,
+			false,
+			0,
+			"shouldn't seek past the beginning of the collection",
+		);
+
+		collection.pos = 5;
+		inner(
+			&mut collection,
+			true,
+			4,
+			"should seek backward if within the bounds of the collection",
+		);
+
+		collection.pos = usize::MAX;
+		inner(
+			&mut collection,
+			false,
+			usize::MAX,
+			"shouldn't seek if outside the bounds of the collection",
+		);
+	}
+
+	#[test]
+	fn seek_relative() {
+		fn inner(
+			collection: &mut TestCollection,
+			offset: isize,
+			expected_result: Option<usize>,
+			expected_pos: usize,
+			error_message: &'static str,
+		) {
+			let seek_res = collection.seek_relative(offset).ok();
+			assert_eq!(seek_res, expected_result, "{error_message}");
+			assert_eq!(
+				collection.pos, expected_pos,
+				"the relative seek did not position the cursor as expected"
+			);
+		}
+
+		let mut collection = self::test_collection();
+
+		inner(&mut collection, 0, Some(0), 0, "shouldn't move at all");
+
+		collection.pos = 5;
+		inner(
+			&mut collection,
+			-2,
+			Some(3),
+			3,
+			"should move when within the bounds of the collection",
+		);
+		inner(
+			&mut collection,
+			2,
+			Some(5),
+			5,
+			"should move when within the bounds of the collection",
+		);
+
+		inner(&mut collection, 0, Some(5), 5, "shouldn't move at all");
+
+		collection.pos = 5;
+		inner(
+			&mut collection,
+			isize::MAX,
+			None,
+			5,
+			"shouldn't move past the end of the collection",
+		);
+		inner(
+			&mut collection,
+			-isize::MAX,
+			None,
+			5,
+			"shouldn't move before the beginning of the collection",
+		);
+
+		collection.pos = usize::MAX;
+		inner(
+			&mut collection,
+			5,
+			None,
+			usize::MAX,
+			"shouldn't move when outside the bounds of the collection",
+		);
+		inner(
+			&mut collection,
+			-5,
+			None,
+			usize::MAX,
+			"shouldn't move when outside the bounds of the collection",
+		);
+	}
+
+	#[test]
+	fn seek_forward_one() {
+		fn inner(
+			collection: &mut TestCollection,
+			should_succeed: bool,
+			expected_new_pos: usize,
+			error_message: &'static str,
+		) {
+			let seek_success = collection.seek_forward_one();
+			assert_eq!(seek_success, should_succeed, "{error_message}");
+			assert_eq!(
+				collection.pos, expected_new_pos,
+				"cursor was not moved to the expected position"
+			);
+		}
+
+		let mut collection = self::test_collection();
+
+		inner(
+			&mut collection,
+			true,
+			1,
+			"should seek forward when an item is available",
+		);
+
+		collection.pos = 5;
+		inner(
+			&mut collection,
+			true,
+			6,
+			"should seek forward when an item is available",
+		);
+
+		let collection_len = collection.inner.len();
+
+		collection.pos = collection_len - 1;
+		inner(
+			&mut collection,
+			true,
+			collection_len,
+			"should seek one past the end of the bounds of the collection",
+		);
+
+		collection.pos = collection_len;
+		inner(
+			&mut collection,
+			false,
+			collection_len,
+			"shouldn't seek past one index past the end of the bounds of the collection",
+		);
+
+		collection.pos = usize::MAX;
+		inner(
+			&mut collection,
+			false,
+			usize::MAX,
+			"shouldn't seek at all past `len()` of the collection",
+		);
+	}
+
+	macro_rules! __seek_to {
+		($method:tt, $initial_pos:expr, $expected_pos:expr) => {
+			let mut collection = self::test_collection();
+			collection.pos = $initial_pos;
+			collection.$method();
+			assert_eq!(collection.pos, $expected_pos);
+		};
+	}
+
+	#[test]
+	fn seek_to_start() {
+		// seek_to_start should ALWAYS succeed
+		__seek_to!(seek_to_start, 5, 0);
+		__seek_to!(seek_to_start, usize::MAX, 0);
+	}
+
+	#[test]
+	fn seek_to_last_item() {
+		let expected_pos = self::test_vec().len() - 1;
+		__seek_to!(seek_to_last_item, 5, expected_pos);
+		__seek_to!(seek_to_last_item, usize::MAX, expected_pos);
+	}
+
+	#[test]
+	fn seek_to_end() {
+		// seek_to_end should ALWAYS succeed
+		let expected_pos = self::test_collection().inner.len();
+		__seek_to!(seek_to_end, 5, expected_pos);
+		__seek_to!(seek_to_end, usize::MAX, expected_pos);
+	}
+
+	#[test]
+	fn get_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		// We deliberately request one item past the end, to test if that's also the same
+		for i in 0..=(test_vec.len()) {
+			collection.pos = i;
+			let test_vec_get = test_vec.get(i);
+			let collection_get = collection.get_item_at_cursor();
+
+			assert_eq!(
+				test_vec_get, collection_get,
+				"should get the same item from the same index (index = `{i}`)"
+			);
+		}
+	}
+
+	#[test]
+	fn scroll_and_read() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection.scroll_and_read(3),
+			(3, Some(&3)),
+			"should move the full distance when within the bounds of the collection"
+		);
+		assert_eq!(
+			collection.scroll_and_read(-1),
+			(-1, Some(&2)),
+			"should move the full distance when within the bounds of the collection"
+		);
+
+		assert_eq!(
+			collection.scroll_and_read(100),
+			(8, None),
+			"should stop one past the last item, and report the clamped distance"
+		);
+		assert_eq!(collection.pos, 10);
+
+		assert_eq!(
+			collection.scroll_and_read(-100),
+			(-10, Some(&0)),
+			"should stop at the beginning, and report the clamped distance"
+		);
+		assert_eq!(collection.pos, 0);
+
+		assert_eq!(
+			collection.scroll_and_read(isize::MIN),
+			(0, Some(&0)),
+			"shouldn't move when already at the beginning"
+		);
+	}
+
+	#[test]
+	fn clear() {
+		let mut test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		test_vec.clear();
+		collection.clear();
+
+		assert_eq!(collection.inner, test_vec);
+	}
+
+	#[test]
+	fn get_item_at_cursor_mut() {
+		let mut test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		// We deliberately request one item past the end, to test if that's also the same
+		for i in 0..=(test_vec.len()) {
+			collection.pos = i;
+			let test_vec_get = test_vec.get_mut(i);
+			let collection_get = collection.get_item_at_cursor_mut();
+
+			assert_eq!(
+				test_vec_get, collection_get,
+				"should get the same item from the same index (index = `{i}`)"
+			);
+		}
+	}
+
+	fn __set_item(mut collection: TestCollection, mut test_vec: TestVec) {
+		const AT_POS: usize = 5;
+		const TO_VALUE: i32 = 52345;
+
+		test_vec[AT_POS] = TO_VALUE;
+		collection.pos = AT_POS;
+		collection.set_item_at_cursor(TO_VALUE);
+
+		assert_eq!(
+			collection.inner.get(AT_POS),
+			Some(&TO_VALUE),
+			"should modify the inner collection to have the correct value at the head"
+		);
+		assert_eq!(collection.inner, test_vec, "should set only one value");
+	}
+
+	#[test]
+	fn set_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let collection = self::test_collection();
+
+		self::__set_item(collection, test_vec);
+	}
+
+	fn __insert_item(mut collection: TestCollection, mut test_vec: TestVec) {
+		const AT_POS: usize = 5;
+		const TO_VALUE: i32 = 52345;
+
+		test_vec.insert(AT_POS, TO_VALUE);
+		collection.pos = AT_POS;
+		collection.insert_item_at_cursor(TO_VALUE);
+
+		assert_eq!(
+			collection.inner.get(AT_POS),
+			Some(&TO_VALUE),
+			"should modify the inner collection to have the correct value at the head"
+		);
+		assert_eq!(collection.inner, test_vec, "should insert only one value");
+	}
+
+	#[test]
+	fn insert_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let collection = self::test_collection();
+
+		self::__insert_item(collection, test_vec);
+	}
+
+	#[test]
+	fn set_or_insert_item_at_cursor() {
+		let test_vec = self::test_vec();
+		let collection = self::test_collection();
+
+		self::__set_item(collection.clone(), test_vec.clone());
+		self::__insert_item(collection.clone(), test_vec.clone());
+	}
+
+	#[test]
+	fn remove_item_at_cursor() {
+		const AT_POS: usize = 5;
+
+		let mut test_vec = self::test_vec();
+		let mut collection = self::test_collection();
+
+		let test_vec_res = test_vec.remove(5);
+		collection.pos = AT_POS;
+		let collection_res = collection.remove_item_at_cursor();
+		assert_eq!(
+			collection_res,
+			Some(test_vec_res),
+			"should return the right value"
+		);
+		assert_eq!(collection.inner, test_vec, "should remove only one value");
+
+		// Additionally, test that this does NOT panic when out-of-bounds
+		collection.pos = collection.inner.len() * 2;
+		let collection_res = collection.remove_item_at_cursor();
+		assert_eq!(
+			collection_res, None,
+			"should return `None` if the head was out-of-bounds"
+		);
+	}
+
+	#[test]
+	fn push_front() {
+		let mut collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		collection.pos = 6;
+		let item_before = collection.get_item_at_cursor().copied();
+
+		collection.push_front(52345);
+		assert_eq!(collection.inner.front(), Some(&52345));
+		assert_eq!(collection.pos, 7, "should move the cursor forward by one");
+		assert_eq!(
+			collection.get_item_at_cursor().copied(),
+			item_before,
+			"should keep the cursor on the same item"
+		);
+
+		collection.seek_to_end();
+		collection.push_front(52345);
+		assert!(
+			collection.is_cursor_at_end(),
+			"should keep the cursor one past the last item"
+		);
+	}
+
+	#[test]
+	fn push_back() {
+		let mut collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		collection.pos = 6;
+
+		collection.push_back(52345);
+		assert_eq!(collection.inner.back(), Some(&52345));
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn pop_front() {
+		let mut collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		collection.pos = 6;
+		let item_before = collection.get_item_at_cursor().copied();
+
+		assert_eq!(collection.pop_front(), Some(0));
+		assert_eq!(collection.pos, 5, "should move the cursor backward by one");
+		assert_eq!(
+			collection.get_item_at_cursor().copied(),
+			item_before,
+			"should keep the cursor on the same item"
+		);
+
+		collection.pos = 0;
+		assert_eq!(collection.pop_front(), Some(1));
+		assert_eq!(collection.pos, 0, "should keep the cursor at the front");
+
+		collection.clear();
+		assert_eq!(
+			collection.pop_front(),
+			None,
+			"should return `None` on an empty collection"
+		);
+	}
+
+	#[test]
+	fn pop_back() {
+		let mut collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		collection.pos = 6;
+
+		assert_eq!(collection.pop_back(), Some(6));
+		assert_eq!(
+			collection.pos, 6,
+			"shouldn't move the cursor when before the back"
+		);
+
+		collection.seek_to_end();
+		assert_eq!(collection.pop_back(), Some(7));
+		assert!(
+			collection.is_cursor_at_end(),
+			"should clamp the cursor to one past the new last item"
+		);
+
+		collection.clear();
+		assert_eq!(
+			collection.pop_back(),
+			None,
+			"should return `None` on an empty collection"
+		);
+	}
+
+	#[test]
+	fn rposition_before_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 7;
+
+		assert_eq!(
+			collection.rposition_before_cursor(|&item| item < 5),
+			Some(4),
+			"should find the nearest match before the cursor"
+		);
+		assert_eq!(
+			collection.rposition_before_cursor(|&item| item == 8),
+			None,
+			"shouldn't consider the item at the cursor"
+		);
+		assert_eq!(
+			collection.rposition_before_cursor(|&item| item == 7),
+			None,
+			"shouldn't consider items after the cursor"
+		);
+		assert_eq!(collection.pos, 7, "shouldn't move the cursor");
+
+		collection.pos = 0;
+		assert_eq!(
+			collection.rposition_before_cursor(|_| true),
+			None,
+			"should find nothing when at the beginning"
+		);
+	}
+
+	#[test]
+	fn max_by_key() {
+		let collection = self::test_collection();
+		assert_eq!(collection.max_by_key(|&item| item), Some((6, &9)));
+		assert_eq!(
+			collection.max_by_key(|&item| item % 2),
+			Some((1, &1)),
+			"should return the first item on ties"
+		);
+
+		let empty_collection = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(
+			empty_collection.max_by_key(|&item| item),
+			None,
+			"should return `None` on an empty collection"
+		);
+	}
+
+	#[test]
+	fn min_by_key() {
+		let collection = self::test_collection();
+		assert_eq!(collection.min_by_key(|&item| item), Some((0, &0)));
+		assert_eq!(
+			collection.min_by_key(|&item| item / 3),
+			Some((0, &0)),
+			"should return the first item on ties"
+		);
+		assert_eq!(
+			collection.min_by_key(|&item| -(item / 4)),
+			Some((6, &9)),
+			"should return the first item on ties"
+		);
+
+		let empty_collection = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(
+			empty_collection.min_by_key(|&item| item),
+			None,
+			"should return `None` on an empty collection"
+		);
+	}
+
+	#[test]
+	fn seek_to_start_of_run() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 1, 2, 2, 2, 3]));
+
+		collection.pos = 3;
+		collection.seek_to_start_of_run();
+		assert_eq!(
+			collection.pos, 2,
+			"should move to the first item of the run"
+		);
+
+		collection.seek_to_start_of_run();
+		assert_eq!(
+			collection.pos, 2,
+			"shouldn't move when already at the start"
+		);
+
+		collection.pos = 1;
+		collection.seek_to_start_of_run();
+		assert_eq!(
+			collection.pos, 0,
+			"should move to the first item of a run at the beginning"
+		);
+
+		collection.pos = 6;
+		collection.seek_to_start_of_run();
+		assert_eq!(
+			collection.pos, 6,
+			"shouldn't move when one past the last item"
+		);
+
+		let mut empty_collection = CollectionCursor::new(Vec::<i32>::new());
+		empty_collection.seek_to_start_of_run();
+		assert_eq!(
+			empty_collection.pos, 0,
+			"shouldn't move on an empty collection"
+		);
+	}
+
+	#[test]
+	fn seek_to_end_of_run() {
+		let mut collection = CollectionCursor::new(Vec::from([1, 1, 2, 2, 2, 3]));
+
+		collection.pos = 3;
+		collection.seek_to_end_of_run();
+		assert_eq!(collection.pos, 4, "should move to the last item of the run");
+
+		collection.seek_to_end_of_run();
+		assert_eq!(collection.pos, 4, "shouldn't move when already at the end");
+
+		collection.pos = 5;
+		collection.seek_to_end_of_run();
+		assert_eq!(
+			collection.pos, 5,
+			"shouldn't move past the last item of the collection"
+		);
+
+		collection.pos = 6;
+		collection.seek_to_end_of_run();
+		assert_eq!(
+			collection.pos, 6,
+			"shouldn't move when one past the last item"
+		);
+
+		let mut empty_collection = CollectionCursor::new(Vec::<i32>::new());
+		empty_collection.seek_to_end_of_run();
+		assert_eq!(
+			empty_collection.pos, 0,
+			"shouldn't move on an empty collection"
+		);
+	}
+
+	#[test]
+	fn as_ref() {
+		fn takes_as_ref(collection: impl AsRef<TestVec>) -> usize {
+			collection.as_ref().len()
+		}
+
+		let collection = self::test_collection();
+		assert_eq!(collection.as_ref(), &self::test_vec());
+		assert_eq!(takes_as_ref(&collection), self::test_vec().len());
+	}
+
+	#[test]
+	fn as_mut() {
+		fn takes_as_mut(mut collection: impl AsMut<TestVec>) {
+			collection.as_mut()[0] = 52345;
+		}
+
+		let mut collection = self::test_collection();
+		assert_eq!(collection.as_mut(), &mut self::test_vec());
+
+		takes_as_mut(&mut collection);
+		assert_eq!(
+			collection.inner[0], 52345,
+			"should modify the inner collection"
+		);
+	}
+
+	#[test]
+	fn iter_step_by_from_cursor() {
+		let mut collection = self::test_collection();
+
+		assert!(
+			collection.iter_step_by_from_cursor(2).eq(&[0, 2, 4, 9, 7]),
+			"should yield every second item from the cursor"
+		);
+
+		collection.pos = 3;
+		assert!(
+			collection.iter_step_by_from_cursor(2).eq(&[3, 5, 8, 6]),
+			"should start from the cursor"
+		);
+		assert!(
+			collection.iter_step_by_from_cursor(100).eq(&[3]),
+			"should yield only the item at the cursor when stepping past the end"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.iter_step_by_from_cursor(2).next(),
+			None,
+			"should yield nothing when one past the last item"
+		);
+	}
+
+	#[test]
+	#[should_panic = "assertion failed: step != 0"]
+	fn iter_step_by_from_cursor_zero_step() {
+		let collection = self::test_collection();
+		let _ = collection.iter_step_by_from_cursor(0);
+	}
+
+	#[test]
+	fn remove_at_cursor_if() {
+		let mut collection = self::test_collection();
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.remove_at_cursor_if(|_| true),
+			None,
+			"should return `None` when there is no item at the cursor"
+		);
+
+		collection.pos = 5;
+		assert_eq!(
+			collection.remove_at_cursor_if(|&item| item == 52345),
+			None,
+			"should return `None` when the predicate fails"
+		);
+		assert_eq!(
+			collection.inner,
+			self::test_vec(),
+			"shouldn't remove anything when the predicate fails"
+		);
+
+		let mut test_vec = self::test_vec();
+		let test_vec_res = test_vec.remove(5);
+		assert_eq!(
+			collection.remove_at_cursor_if(|&item| item == 5),
+			Some(test_vec_res),
+			"should return the item when the predicate succeeds"
+		);
+		assert_eq!(collection.inner, test_vec, "should remove only one value");
+	}
+
+	#[test]
+	fn fill_at_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(collection.fill_at_cursor(3, 52345), 3);
+		assert_eq!(collection.inner, [0, 1, 52345, 52345, 52345, 5, 9, 8, 7, 6]);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+
+		collection.pos = 8;
+		assert_eq!(
+			collection.fill_at_cursor(5, 12345),
+			2,
+			"should only count the slots actually written"
+		);
+		assert_eq!(
+			collection.inner,
+			[0, 1, 52345, 52345, 52345, 5, 9, 8, 12345, 12345],
+			"shouldn't resize the collection"
+		);
+
+		collection.pos = collection.inner.len();
+		assert_eq!(
+			collection.fill_at_cursor(usize::MAX, 0),
+			0,
+			"shouldn't write anything when one past the last item"
+		);
+
+		#[cfg(feature = "core")]
+		{
+			let mut array_collection = CollectionCursor::new([0; 4]);
+			array_collection.pos = 1;
+			assert_eq!(array_collection.fill_at_cursor(usize::MAX, 1), 3);
+			assert_eq!(
+				array_collection.inner,
+				[0, 1, 1, 1],
+				"should work on fixed arrays"
+			);
+		}
+	}
+
+	#[test]
+	fn rotate_cursor_to_front() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+		collection.save_checkpoint();
+		collection.pos = 6;
+
+		collection.rotate_cursor_to_front();
+		assert_eq!(collection.inner, [9, 8, 7, 6, 0, 1, 2, 3, 4, 5]);
+		assert_eq!(collection.pos, 0, "should move the cursor to the front");
+		assert_eq!(
+			collection.checkpoint(),
+			Some(6),
+			"should keep the checkpoint on the same item"
+		);
+
+		collection.seek_to_end();
+		collection.rotate_cursor_to_front();
+		assert_eq!(
+			collection.inner,
+			[9, 8, 7, 6, 0, 1, 2, 3, 4, 5],
+			"shouldn't change the order when one past the last item"
+		);
+		assert_eq!(collection.pos, 0, "should move the cursor to the front");
+
+		let mut deque_collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		deque_collection.pos = 3;
+		deque_collection.save_checkpoint();
+
+		deque_collection.rotate_cursor_to_front();
+		assert_eq!(deque_collection.inner, [3, 4, 5, 9, 8, 7, 6, 0, 1, 2]);
+		assert_eq!(
+			deque_collection.pos, 0,
+			"should move the cursor to the front"
+		);
+		assert_eq!(
+			deque_collection.checkpoint(),
+			Some(0),
+			"should keep the checkpoint on the same item"
+		);
+
+		deque_collection.pos = 8;
+		deque_collection.rotate_cursor_to_front();
+		assert_eq!(
+			deque_collection.inner,
+			[1, 2, 3, 4, 5, 9, 8, 7, 6, 0],
+			"should move the items after the cursor when there are fewer of them"
+		);
+
+		deque_collection.seek_to_end();
+		deque_collection.rotate_cursor_to_front();
+		assert_eq!(
+			deque_collection.inner,
+			[1, 2, 3, 4, 5, 9, 8, 7, 6, 0],
+			"shouldn't change the order when one past the last item"
+		);
+		assert_eq!(
+			deque_collection.pos, 0,
+			"should move the cursor to the front"
+		);
+	}
+
+	#[test]
+	fn try_seek_forward_n() {
+		let mut collection = self::test_collection();
+		let collection_len = collection.inner.len();
+
+		assert!(collection.try_seek_forward_n(3));
+		assert_eq!(collection.pos, 3, "should move forward by `n`");
+
+		assert!(collection.try_seek_forward_n(collection_len - 3));
+		assert_eq!(
+			collection.pos, collection_len,
+			"should move to one index past the last item"
+		);
+
+		collection.pos = 5;
+		assert!(
+			!collection.try_seek_forward_n(collection_len - 4),
+			"shouldn't move past one index past the last item"
+		);
+		assert!(
+			!collection.try_seek_forward_n(usize::MAX),
+			"shouldn't overflow"
+		);
+		assert_eq!(
+			collection.pos, 5,
+			"shouldn't move the cursor at all when failing"
+		);
+	}
+
+	#[test]
+	fn try_seek_backward_n() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert!(collection.try_seek_backward_n(2));
+		assert_eq!(collection.pos, 3, "should move backward by `n`");
+
+		assert!(collection.try_seek_backward_n(3));
+		assert_eq!(collection.pos, 0, "should move to the beginning");
+
+		collection.pos = 5;
+		assert!(
+			!collection.try_seek_backward_n(6),
+			"shouldn't move before the beginning"
+		);
+		assert!(
+			!collection.try_seek_backward_n(usize::MAX),
+			"shouldn't underflow"
+		);
+		assert_eq!(
+			collection.pos, 5,
+			"shouldn't move the cursor at all when failing"
+		);
+	}
+
+	#[test]
+	fn get_ref_at() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		assert_eq!(
+			collection.get_ref_at(3),
+			collection.get_item_at_cursor(),
+			"should get the same item as the cursor when given the cursor's position"
+		);
+		assert_eq!(collection.get_ref_at(6), Some(&9));
+		assert_eq!(collection.get_ref_at(collection.inner.len()), None);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn get_mut_at() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		assert_eq!(collection.get_mut_at(3), Some(&mut 3));
+		*collection.get_mut_at(6).unwrap() = 52345;
+		assert_eq!(
+			collection.inner[6], 52345,
+			"should modify the item at the index"
+		);
+		assert_eq!(collection.get_mut_at(collection.inner.len()), None);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn skip_forward_while() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 0, 1, 2, 0, 0, 0]));
+
+		assert_eq!(
+			collection.skip_forward_while(|&item| item == 0),
+			2,
+			"should skip a leading run"
+		);
+		assert_eq!(
+			collection.pos, 2,
+			"should stop on the first non-matching item"
+		);
+
+		assert_eq!(
+			collection.skip_forward_while(|&item| item == 0),
+			0,
+			"shouldn't skip when the item at the cursor doesn't match"
+		);
+		assert_eq!(collection.pos, 2);
+
+		collection.pos = 4;
+		assert_eq!(
+			collection.skip_forward_while(|&item| item == 0),
+			3,
+			"should skip a trailing run"
+		);
+		assert!(
+			collection.is_cursor_at_end(),
+			"should stop one past the last item when the run reaches the end"
+		);
+
+		assert_eq!(
+			collection.skip_forward_while(|_| true),
+			0,
+			"shouldn't skip anything when one past the last item"
+		);
+	}
+
+	#[test]
+	fn skip_backward_while() {
+		let mut collection = CollectionCursor::new(Vec::from([0, 0, 1, 2, 0, 0, 0]));
+		collection.seek_to_end();
+
+		assert_eq!(
+			collection.skip_backward_while(|&item| item == 0),
+			3,
+			"should skip a trailing run"
+		);
+		assert_eq!(
+			collection.pos, 4,
+			"should stop just after the first non-matching item"
+		);
+
+		collection.pos = 2;
+		assert_eq!(
+			collection.skip_backward_while(|&item| item == 0),
+			2,
+			"should skip a leading run"
+		);
+		assert_eq!(collection.pos, 0, "should stop at the beginning");
+
+		assert_eq!(
+			collection.skip_backward_while(|_| true),
+			0,
+			"shouldn't skip anything when at the beginning"
+		);
+	}
+
+	#[test]
+	fn take() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		let expected = collection.clone();
+
+		let taken = collection.take();
+		assert_eq!(taken, expected, "should return the original cursor");
+		assert_eq!(collection.pos, 0, "should leave the cursor at index `0`");
+		assert_eq!(
+			collection.inner,
+			TestVec::new(),
+			"should leave an empty collection behind"
+		);
+	}
+
+	#[test]
+	fn indices_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 7;
+
+		let indices = collection.indices_from_cursor();
+		assert_eq!(
+			indices.len(),
+			3,
+			"should report the number of remaining indices"
+		);
+		assert!(indices.eq(7..10));
+
+		collection.seek_to_end();
+		assert_eq!(
+			collection.indices_from_cursor().len(),
+			0,
+			"should yield nothing when one past the last item"
+		);
+
+		collection.pos = usize::MAX;
+		assert_eq!(
+			collection.indices_from_cursor().len(),
+			0,
+			"should yield nothing when out-of-bounds"
+		);
+
+		let empty_collection = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(
+			empty_collection.indices_from_cursor().len(),
+			0,
+			"should yield nothing on an empty collection"
+		);
+	}
+
+	#[test]
+	fn merge_sorted() {
+		let mut collection = CollectionCursor::new(Vec::from([2, 4, 6]));
+		collection.pos = 2;
+
+		collection.merge_sorted(Vec::from([1, 3, 5]));
+		assert_eq!(
+			collection.inner,
+			[1, 2, 3, 4, 5, 6],
+			"should be fully sorted"
+		);
+		assert_eq!(
+			collection.pos, 2,
+			"should keep the cursor at the same index"
+		);
+
+		collection.merge_sorted(Vec::from([0, 3, 7, 8]));
+		assert_eq!(collection.inner, [0, 1, 2, 3, 3, 4, 5, 6, 7, 8]);
+
+		collection.merge_sorted(Vec::new());
+		assert_eq!(
+			collection.inner,
+			[0, 1, 2, 3, 3, 4, 5, 6, 7, 8],
+			"merging an empty collection shouldn't change anything"
+		);
+
+		let mut empty_collection = CollectionCursor::new(VecDeque::new());
+		empty_collection.merge_sorted(VecDeque::from([1, 2, 3]));
+		assert_eq!(empty_collection.inner, [1, 2, 3]);
+	}
+
+	#[test]
+	fn merge_sorted_saved_positions() {
+		let mut collection = CollectionCursor::new(Vec::from([2, 4, 6]));
+		collection.pos = 1;
+		collection.save_checkpoint();
+		collection.seek_to_end();
+		collection.set_mark("end");
+		collection.pos = 1;
+		collection.set_mark("four");
+
+		collection.merge_sorted(Vec::from([1, 3, 4, 5, 7]));
+		assert_eq!(collection.inner, [1, 2, 3, 4, 4, 5, 6, 7]);
+		assert_eq!(
+			collection.checkpoint(),
+			Some(3),
+			"should keep the checkpoint on the same item"
+		);
+		assert_eq!(
+			collection.mark("four"),
+			Some(3),
+			"should place our items before equal items"
+		);
+		assert_eq!(
+			collection.mark("end"),
+			Some(8),
+			"should keep marks one past the last item there"
+		);
+		assert_eq!(collection.restore_checkpoint(), Some(3));
+	}
+
+	#[test]
+	fn merge_sorted_stability() {
+		/// An item which is only ordered by its key, so we can tell equal items apart by their tag.
+		#[derive(Debug)]
+		struct Keyed(i32, char);
+
+		impl PartialEq for Keyed {
+			fn eq(&self, other: &Self) -> bool {
+				self.0 == other.0
+			}
+		}
+
+		impl Eq for Keyed {}
+
+		impl PartialOrd for Keyed {
+			fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+
+		impl Ord for Keyed {
+			fn cmp(&self, other: &Self) -> Ordering {
+				self.0.cmp(&other.0)
+			}
+		}
+
+		let mut collection = CollectionCursor::new(Vec::from([Keyed(1, 'a'), Keyed(2, 'a')]));
+		collection.merge_sorted(Vec::from([Keyed(1, 'b'), Keyed(2, 'b')]));
+
+		let tags = collection.inner.iter().map(|item| item.1);
+		assert!(
+			tags.eq(['a', 'b', 'a', 'b']),
+			"should place the inner collection's items before equal items from `other`"
+		);
+	}
+
+	#[test]
+	fn set_items_from_slice() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(collection.set_items_from_slice(&[10, 11, 12]), 3);
+		assert_eq!(collection.inner, [0, 1, 10, 11, 12, 5, 9, 8, 7, 6]);
+		assert_eq!(collection.pos, 2, "shouldn't move the cursor");
+
+		collection.pos = 8;
+		assert_eq!(
+			collection.set_items_from_slice(&[20, 21, 22, 23]),
+			2,
+			"should only count the slots actually written"
+		);
+		assert_eq!(
+			collection.inner,
+			[0, 1, 10, 11, 12, 5, 9, 8, 20, 21],
+			"shouldn't resize the collection"
+		);
+
+		#[cfg(feature = "core")]
+		{
+			let mut array_collection = CollectionCursor::new([0; 4]);
+			array_collection.pos = 1;
+			assert_eq!(array_collection.set_items_from_slice(&[1, 2, 3, 4]), 3);
+			assert_eq!(
+				array_collection.inner,
+				[0, 1, 2, 3],
+				"should work on fixed arrays"
+			);
+		}
+	}
+
+	#[test]
+	fn position_saturating_add() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(collection.position_saturating_add(3), 8);
+		assert_eq!(
+			collection.position_saturating_add(5),
+			10,
+			"should allow one index past the last item"
+		);
+		assert_eq!(
+			collection.position_saturating_add(6),
+			10,
+			"should clamp to one index past the last item"
+		);
+		assert_eq!(
+			collection.position_saturating_add(usize::MAX),
+			10,
+			"shouldn't overflow"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn position_saturating_sub() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(collection.position_saturating_sub(3), 2);
+		assert_eq!(
+			collection.position_saturating_sub(6),
+			0,
+			"should clamp to the beginning"
+		);
+		assert_eq!(
+			collection.position_saturating_sub(usize::MAX),
+			0,
+			"shouldn't underflow"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
+	}
+
+	#[test]
+	fn collect_remaining() {
+		let mut collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		collection.pos = 6;
+
+		let collected: Vec<i32> = collection.collect_remaining();
+		assert_eq!(collected, [9, 8, 7, 6], "should collect the items in order");
+		assert_eq!(
+			collection.inner,
+			[0, 1, 2, 3, 4, 5],
+			"should remove the items"
+		);
+		assert!(
+			collection.is_cursor_at_end(),
+			"should leave the cursor one past the last item"
+		);
+
+		let collected: Vec<i32> = collection.collect_remaining();
+		assert!(
+			collected.is_empty(),
+			"should collect nothing when one past the last item"
+		);
+	}
+
+	#[test]
+	fn is_valid_position() {
+		let collection = self::test_collection();
+		let collection_len = collection.inner.len();
+
+		assert!(collection.is_valid_position(0));
+		assert!(collection.is_valid_position(collection_len - 1));
+		assert!(
+			collection.is_valid_position(collection_len),
+			"should accept one index past the last item"
+		);
+		assert!(!collection.is_valid_position(collection_len + 1));
+		assert!(!collection.is_valid_position(usize::MAX));
+	}
+
+	#[test]
+	fn clamp_into_bounds() {
+		let mut collection = self::test_collection();
+		let collection_len = collection.inner.len();
+
+		collection.pos = collection_len - 1;
+		collection.clamp_into_bounds();
+		assert_eq!(
+			collection.pos,
+			collection_len - 1,
+			"shouldn't move when in bounds"
+		);
+
+		collection.pos = collection_len;
+		collection.clamp_into_bounds();
+		assert_eq!(
+			collection.pos, collection_len,
+			"shouldn't move when one past the last item"
+		);
+
+		collection.pos = collection_len + 1;
+		collection.clamp_into_bounds();
+		assert_eq!(
+			collection.pos, collection_len,
+			"should move to one past the last item when past it"
+		);
+	}
+
+	#[test]
+	fn find() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		assert_eq!(
+			collection.find(SearchOrigin::Cursor, |&item| item == 2),
+			None,
+			"shouldn't find items before the cursor when searching from the cursor"
+		);
+		assert_eq!(collection.pos, 5, "shouldn't move when nothing matches");
+
+		assert_eq!(
+			collection.find(SearchOrigin::Start, |&item| item == 2),
+			Some(2),
+			"should find items before the cursor when searching from the start"
+		);
+		assert_eq!(collection.pos, 2, "should move onto the match");
+
+		assert_eq!(
+			collection.find(SearchOrigin::Cursor, |&item| item % 2 == 0),
+			Some(2),
+			"should consider the item at the cursor when searching from the cursor"
+		);
+
+		assert_eq!(
+			collection.find(SearchOrigin::Cursor, |&item| item > 6),
+			Some(6),
+			"should find the first match after the cursor"
+		);
+		assert_eq!(collection.pos, 6, "should move onto the match");
+	}
+
+	#[test]
+	fn overwrite_cycle() {
+		let mut collection = self::test_collection();
+		collection.pos = 1;
+
+		assert_eq!(collection.overwrite_cycle(&[10, 11], 5), 5);
+		assert_eq!(
+			collection.inner,
+			[0, 10, 11, 10, 11, 10, 9, 8, 7, 6],
+			"should repeat the pattern"
+		);
+		assert_eq!(collection.pos, 1, "shouldn't move the cursor");
+
+		collection.pos = 6;
+		assert_eq!(
+			collection.overwrite_cycle(&[20, 21, 22], usize::MAX),
+			4,
+			"should only count the slots actually written"
+		);
+		assert_eq!(
+			collection.inner,
+			[0, 10, 11, 10, 11, 10, 20, 21, 22, 20],
+			"shouldn't resize the collection"
+		);
+	}
+
+	#[test]
+	#[should_panic = "pattern must not be empty"]
+	fn overwrite_cycle_empty_pattern() {
+		let mut collection = self::test_collection();
+		collection.overwrite_cycle(&[], 5);
+	}
+
+	#[test]
+	fn split_at_cursor_ref() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection.split_at_cursor_ref(),
+			(&[][..], &self::test_vec()[..]),
+			"should have an empty first half when at the beginning"
+		);
+
+		collection.pos = 4;
+		assert_eq!(
+			collection.split_at_cursor_ref(),
+			(&[0, 1, 2, 3][..], &[4, 5, 9, 8, 7, 6][..])
+		);
+
+		collection.seek_to_end();
+		assert_eq!(
+			collection.split_at_cursor_ref(),
+			(&self::test_vec()[..], &[][..]),
+			"should have an empty second half when one past the last item"
+		);
+	}
+
+	#[test]
+	fn split_first_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 7;
+
+		assert_eq!(
+			collection.split_first_from_cursor(),
+			Some((&8, &[7, 6][..]))
+		);
+
+		collection.seek_to_last_item();
+		assert_eq!(collection.split_first_from_cursor(), Some((&6, &[][..])));
+
+		collection.seek_to_end();
+		assert_eq!(
+			collection.split_first_from_cursor(),
+			None,
+			"should return `None` when one past the last item"
+		);
+	}
+
+	#[test]
+	fn item_at_cursor_or_insert_with() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+
+		let item = collection.item_at_cursor_or_insert_with(|| 52345);
+		assert_eq!(item, &mut 5, "should return the existing item");
+		*item = 12345;
+		assert_eq!(
+			collection.inner[5], 12345,
+			"should give mutable access to the item"
+		);
+		assert_eq!(
+			collection.inner.len(),
+			self::test_vec().len(),
+			"shouldn't insert when an item exists"
+		);
+
+		collection.seek_to_end();
+		assert_eq!(
+			collection.item_at_cursor_or_insert_with(|| 52345),
+			&mut 52345,
+			"should insert and return the new item when one past the last item"
+		);
+		assert_eq!(collection.inner.last(), Some(&52345));
+		assert_eq!(collection.inner.len(), self::test_vec().len() + 1);
+	}
+
+	#[test]
+	#[should_panic = "cursor position 11 is past the end of the collection (length 10)"]
+	fn item_at_cursor_or_insert_with_out_of_bounds() {
+		let mut collection = self::test_collection();
+		collection.pos = 11;
+		collection.item_at_cursor_or_insert_with(|| 52345);
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn new_const() {
+		const COLLECTION: CollectionCursor<[u8; 4]> = CollectionCursor::new([0; 4]);
+		static STATIC_COLLECTION: CollectionCursor<[u8; 4]> = CollectionCursor::new([1; 4]);
+
+		assert_eq!(COLLECTION.position(), 0);
+		assert_eq!(COLLECTION.get_ref(), &[0; 4]);
+		assert_eq!(STATIC_COLLECTION.position(), 0);
+		assert_eq!(STATIC_COLLECTION.get_item_at_cursor(), Some(&1));
+	}
+
+	#[test]
+	fn shift_position_by() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+		let item_before = collection.get_item_at_cursor().copied();
+
+		// Simulate inserting items before the cursor from outside of the cursor's API
+		collection.get_mut().splice(0..0, [20, 21, 22]);
+		assert_eq!(
+			collection.shift_position_by(3),
+			Ok(9),
+			"should return the new position"
+		);
+		assert_eq!(
+			collection.get_item_at_cursor().copied(),
+			item_before,
+			"should restore the cursor onto the same item"
+		);
+
+		assert!(
+			collection.shift_position_by(-10).is_err(),
+			"shouldn't shift before the beginning"
+		);
+		assert!(
+			collection.shift_position_by(5).is_err(),
+			"shouldn't shift past one index past the last item"
+		);
+		assert_eq!(collection.pos, 9, "shouldn't move when failing");
+	}
+
+	#[test]
+	fn batch_seek() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection.batch_seek(&[
+				SeekFrom::Start(2),
+				SeekFrom::Current(5),
+				SeekFrom::End(-1),
+				SeekFrom::Current(-3),
+			]),
+			Ok(6),
+			"should apply every seek in order"
+		);
+		assert_eq!(collection.pos, 6);
+
+		assert_eq!(
+			collection.batch_seek(&[]),
+			Ok(6),
+			"shouldn't move with no seeks"
+		);
+
+		let result = collection.batch_seek(&[
 			SeekFrom::Start(0),
-			Some(0),
+			SeekFrom::Current(8),
+			SeekFrom::Current(3),
+			SeekFrom::Start(1),
+		]);
+		let (index, error) = result.expect_err("the third seek should be out-of-bounds");
+		assert_eq!(index, 2, "should report the index of the failing seek");
+		assert_eq!(error.seek_from(), SeekFrom::Current(3));
+		assert_eq!(error.target(), Some(11));
+		assert_eq!(error.collection_len(), 10);
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor at all");
+
+		let (index, error) = collection
+			.batch_seek(&[SeekFrom::Current(-7)])
+			.expect_err("seeking before the beginning should fail");
+		assert_eq!(index, 0);
+		assert_eq!(
+			error.target(),
+			None,
+			"should report no target when before the beginning"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn sub_cursor() {
+		let mut collection = CollectionCursor {
+			pos: 7,
+			..CollectionCursor::new(self::test_vec())
+		};
+
+		let mut sub_cursor = collection
+			.sub_cursor(2, 6)
+			.expect("`2..6` should be a valid range");
+		assert_eq!(
+			sub_cursor.position(),
 			0,
-			"`Start(0)` should move the cursor to the start of the collection",
+			"should start at the start of the range"
 		);
+		assert_eq!(sub_cursor.get_ref().len(), 4);
+		assert_eq!(sub_cursor.get_item_at_cursor(), Some(&2));
 
-		inner(
-			&mut collection,
-			SeekFrom::Current(0),
+		sub_cursor.seek_to_last_item();
+		assert_eq!(sub_cursor.get_item_at_cursor(), Some(&5));
+		sub_cursor.set_item_at_cursor(50);
+		sub_cursor.seek_to_start();
+		sub_cursor.set_item_at_cursor(20);
+
+		assert_eq!(
+			collection.inner,
+			[0, 1, 20, 3, 4, 50, 9, 8, 7, 6],
+			"edits should be reflected in the parent collection"
+		);
+		assert_eq!(collection.pos, 7, "shouldn't move the parent cursor");
+
+		assert!(
+			collection
+				.sub_cursor(4, 4)
+				.is_some_and(|c| c.get_ref().is_empty()),
+			"should allow an empty range"
+		);
+		assert!(collection.sub_cursor(0, 10).is_some());
+		assert!(
+			collection.sub_cursor(5, 11).is_none(),
+			"should reject `hi` past the end"
+		);
+		assert!(
+			collection.sub_cursor(6, 5).is_none(),
+			"should reject `lo > hi`"
+		);
+	}
+
+	#[test]
+	fn distance_to_next() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		assert_eq!(
+			collection.distance_to_next(|&item| item == 3),
 			Some(0),
-			0,
-			"`Current(0) shouldn't move the cursor",
+			"should return `0` if the item at the cursor matches"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::Current(7),
-			Some(7),
-			7,
-			"`Current(x)` should move the cursor forward within the bounds of the collection",
+		assert_eq!(collection.distance_to_next(|&item| item > 5), Some(3));
+		assert_eq!(
+			collection.distance_to_next(|&item| item == 1),
+			None,
+			"shouldn't search before the cursor"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::Current(-2),
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
+
+		collection.pos = 10;
+		assert_eq!(
+			collection.distance_to_next(|_| true),
+			None,
+			"should return `None` at the end"
+		);
+	}
+
+	#[test]
+	fn edit() {
+		let mut collection = self::test_collection();
+		collection.pos = 7;
+
+		let removed = collection.edit(|inner| inner.drain(3..).count());
+		assert_eq!(removed, 7, "should return the closure's result");
+		assert_eq!(collection.inner, [0, 1, 2]);
+		assert_eq!(collection.pos, 3, "should clamp the cursor to the end");
+
+		collection.edit_with(ClampPolicy::ToLastItem, |inner| inner.truncate(2));
+		assert_eq!(
+			collection.pos, 1,
+			"should clamp the cursor to the last item"
+		);
+
+		collection.edit(|inner| inner.push(5));
+		assert_eq!(
+			collection.pos, 1,
+			"shouldn't move the cursor when it's within the new bounds"
+		);
+
+		collection.seek_to_end();
+		collection.edit_with(ClampPolicy::ToLastItem, |_| ());
+		assert_eq!(
+			collection.pos, 2,
+			"should move the cursor from one past the last item onto the last item"
+		);
+		collection.seek_to_end();
+		collection.edit(|_| ());
+		assert_eq!(
+			collection.pos, 3,
+			"shouldn't move the cursor from one past the last item with `ToEnd`"
+		);
+	}
+
+	#[test]
+	fn replace_inner() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+		collection.save_checkpoint();
+
+		collection.pos = 8;
+		collection.set_mark("eight");
+
+		let mut buffer = collection.replace_inner(Vec::from([7, 8, 9, 10, 11, 12]));
+		assert_eq!(buffer, self::test_vec(), "should return the old collection");
+		assert_eq!(collection.pos, 6, "should clamp the cursor to the new end");
+		assert_eq!(
+			collection.mark("eight"),
+			Some(6),
+			"should clamp marks to the new end"
+		);
+		assert_eq!(
+			collection.restore_checkpoint(),
+			Some(4),
+			"should keep checkpoints within the new bounds"
+		);
+
+		buffer.clear();
+		buffer.extend([1, 2]);
+		assert_eq!(collection.replace_inner(buffer), [7, 8, 9, 10, 11, 12]);
+		assert_eq!(
+			collection.restore_checkpoint(),
+			Some(2),
+			"should clamp checkpoints to the new end"
+		);
+	}
+
+	#[test]
+	#[allow(deprecated, reason = "checks that the old name still works")]
+	fn replace_tape() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		collection.save_checkpoint();
+		collection.push_checkpoint();
+		collection.pos = 7;
+
+		let old_inner = collection.replace_tape(Vec::from([1, 2, 3]));
+		assert_eq!(
+			old_inner,
+			self::test_vec(),
+			"should return the old collection"
+		);
+		assert_eq!(collection.inner, [1, 2, 3]);
+		assert_eq!(collection.pos, 3, "should clamp the cursor to the new end");
+		assert_eq!(
+			collection.checkpoint(),
+			Some(3),
+			"should clamp checkpoints to the new end"
+		);
+
+		collection.pos = 2;
+		collection.replace_tape(self::test_vec());
+		assert_eq!(
+			collection.pos, 2,
+			"shouldn't move the cursor when it's within the new bounds"
+		);
+		assert_eq!(collection.rollback_to_checkpoint(), Some(3));
+	}
+
+	#[test]
+	fn first_and_last() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		assert_eq!(collection.first(), Some(&0));
+		assert_eq!(collection.last(), Some(&6));
+		*collection.first_mut().unwrap() = 10;
+		*collection.last_mut().unwrap() = 60;
+		assert_eq!(collection.inner, [10, 1, 2, 3, 4, 5, 9, 8, 7, 60]);
+		assert_eq!(collection.pos, 4, "shouldn't move the cursor");
+
+		let mut single = CollectionCursor::new(Vec::from([5]));
+		assert_eq!(single.first(), Some(&5));
+		assert_eq!(
+			single.last(),
+			Some(&5),
+			"should return the same item as `first()`"
+		);
+		assert_eq!(single.first_mut(), Some(&mut 5));
+		assert_eq!(single.last_mut(), Some(&mut 5));
+
+		let mut empty = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(empty.first(), None, "should return `None` when empty");
+		assert_eq!(empty.last(), None, "should return `None` when empty");
+		assert_eq!(empty.first_mut(), None);
+		assert_eq!(empty.last_mut(), None);
+	}
+
+	#[test]
+	fn transpose_at_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		assert!(collection.transpose_at_cursor());
+		assert_eq!(collection.inner, [0, 1, 3, 2, 4, 5, 9, 8, 7, 6]);
+		assert_eq!(collection.pos, 4, "should move the cursor forward by one");
+
+		collection.pos = 9;
+		assert!(
+			collection.transpose_at_cursor(),
+			"should be able to transpose the last two items"
+		);
+		assert_eq!(collection.inner, [0, 1, 3, 2, 4, 5, 9, 8, 6, 7]);
+		assert_eq!(collection.pos, 10);
+
+		assert!(
+			!collection.transpose_at_cursor(),
+			"shouldn't transpose when not pointing at an item"
+		);
+		collection.pos = 0;
+		assert!(
+			!collection.transpose_at_cursor(),
+			"shouldn't transpose at index 0"
+		);
+		assert_eq!(collection.inner, [0, 1, 3, 2, 4, 5, 9, 8, 6, 7]);
+		assert_eq!(collection.pos, 0, "shouldn't move the cursor when refusing");
+	}
+
+	#[test]
+	fn fold_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.fold_from_cursor(0, |acc, &item| acc + item),
+			9 + 8 + 7 + 6,
+			"should only fold the items from the cursor onwards"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
+
+		collection.pos = 10;
+		assert_eq!(
+			collection.fold_from_cursor(42, |acc, &item| acc + item),
+			42,
+			"should return `init` at the end"
+		);
+	}
+
+	#[test]
+	fn insert_slice_at_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
+
+		collection.insert_slice_at_cursor(&[10, 11, 12]);
+		assert_eq!(
+			collection.inner,
+			[0, 1, 2, 10, 11, 12, 3, 4, 5, 9, 8, 7, 6],
+			"should insert the items in order at the cursor"
+		);
+		assert_eq!(collection.pos, 3, "should point at the first inserted item");
+		assert_eq!(collection.get_item_at_cursor(), Some(&10));
+
+		collection.insert_slice_at_cursor(&[]);
+		assert_eq!(collection.inner.len(), 13, "shouldn't insert anything");
+
+		collection.seek_to_end();
+		collection.insert_slice_at_cursor(&[20, 21]);
+		assert_eq!(
+			collection.inner[12..],
+			[6, 20, 21],
+			"should be able to append"
+		);
+		assert_eq!(collection.pos, 13);
+
+		collection.seek_to_start();
+		collection.insert_slice_at_cursor(&[-1]);
+		assert_eq!(
+			collection.inner[..3],
+			[-1, 0, 1],
+			"should be able to prepend"
+		);
+	}
+
+	#[test]
+	fn checkpoint() {
+		let mut collection = self::test_collection();
+		assert_eq!(
+			collection.restore_checkpoint(),
+			None,
+			"should return `None` with no checkpoint saved"
+		);
+		assert_eq!(collection.pos, 0);
+
+		collection.pos = 4;
+		collection.save_checkpoint();
+		assert_eq!(collection.checkpoint(), Some(4));
+
+		collection.seek_to_end();
+		assert_eq!(collection.restore_checkpoint(), Some(4));
+		assert_eq!(collection.pos, 4, "should return to the checkpoint");
+
+		collection.seek_to_start();
+		assert_eq!(
+			collection.restore_checkpoint(),
+			Some(4),
+			"should keep the checkpoint after restoring"
+		);
+
+		collection.pos = 9;
+		collection.save_checkpoint();
+		collection.get_mut().truncate(5);
+		collection.clamp_to_end();
+		collection.seek_to_start();
+		assert_eq!(
+			collection.restore_checkpoint(),
 			Some(5),
-			5,
-			"`Current(-x) should move the cursor backwards within the bounds of the collection",
+			"should clamp the checkpoint if the collection shrunk"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::Current(-5),
-			Some(0),
+
+		collection.clear_checkpoint();
+		assert_eq!(collection.checkpoint(), None);
+		assert_eq!(collection.restore_checkpoint(), None);
+	}
+
+	#[test]
+	fn pairs_from_cursor() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection.pairs_from_cursor().collect::<Vec<_>>(),
+			[(&9, &8), (&8, &7), (&7, &6)],
+			"should yield overlapping pairs up to the last two items"
+		);
+
+		collection.pos = 9;
+		assert_eq!(
+			collection.pairs_from_cursor().count(),
 			0,
-			"`Current(-current_pos) should move the cursor to the start of the collection",
+			"should yield nothing with one item remaining"
+		);
+		collection.pos = 10;
+		assert_eq!(
+			collection.pairs_from_cursor().count(),
+			0,
+			"should yield nothing at the end"
 		);
 
-		// TODO: De-hardcode the expected results
-		inner(
-			&mut collection,
-			SeekFrom::End(0),
-			Some(10),
+		let empty = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(empty.pairs_from_cursor().count(), 0);
+	}
+
+	#[test]
+	fn move_item() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		assert!(
+			collection.move_item(1, 6),
+			"should move an item forwards across the cursor"
+		);
+		assert_eq!(collection.inner, [0, 2, 3, 4, 5, 9, 1, 8, 7, 6]);
+		assert_eq!(collection.pos, 3);
+		assert_eq!(
+			collection.get_item_at_cursor(),
+			Some(&4),
+			"should keep pointing at the same item"
+		);
+
+		assert!(
+			collection.move_item(8, 0),
+			"should move an item backwards across the cursor"
+		);
+		assert_eq!(collection.inner, [7, 0, 2, 3, 4, 5, 9, 1, 8, 6]);
+		assert_eq!(collection.get_item_at_cursor(), Some(&4));
+
+		assert!(
+			collection.move_item(4, 9),
+			"should move the item at the cursor"
+		);
+		assert_eq!(collection.inner, [7, 0, 2, 3, 5, 9, 1, 8, 6, 4]);
+		assert_eq!(collection.pos, 9, "should follow the moved item");
+
+		collection.seek_to_end();
+		assert!(collection.move_item(0, 9));
+		assert_eq!(collection.inner, [0, 2, 3, 5, 9, 1, 8, 6, 4, 7]);
+		assert_eq!(collection.pos, 10, "should stay at the end");
+
+		assert!(
+			!collection.move_item(10, 0),
+			"should refuse an out-of-bounds `from`"
+		);
+		assert!(
+			!collection.move_item(0, 10),
+			"should refuse an out-of-bounds `to`"
+		);
+		assert_eq!(collection.inner, [0, 2, 3, 5, 9, 1, 8, 6, 4, 7]);
+	}
+
+	#[test]
+	fn seek_error() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		let error = collection
+			.seek(SeekFrom::Current(7))
+			.expect_err("seeking past the end should fail");
+		assert_eq!(error.seek_from(), SeekFrom::Current(7));
+		assert_eq!(
+			error.target(),
+			Some(11),
+			"should report the attempted target"
+		);
+		assert_eq!(error.collection_len(), 10);
+
+		let error = collection
+			.seek(SeekFrom::End(-11))
+			.expect_err("seeking before the beginning should fail");
+		assert_eq!(error.seek_from(), SeekFrom::End(-11));
+		assert_eq!(
+			error.target(),
+			None,
+			"should report no target when before the beginning"
+		);
+
+		let error = collection
+			.seek_relative(-5)
+			.expect_err("seeking before the beginning should fail");
+		assert_eq!(error.seek_from(), SeekFrom::Current(-5));
+		assert_eq!(collection.pos, 4, "shouldn't move the cursor when failing");
+	}
+
+	#[test]
+	fn seek_saturating() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(
+			collection.seek_saturating(SeekFrom::Start(4)),
+			4,
+			"should move normally within the bounds of the collection"
+		);
+		assert_eq!(collection.seek_saturating(SeekFrom::Current(3)), 7);
+		assert_eq!(collection.seek_saturating(SeekFrom::End(-2)), 8);
+
+		assert_eq!(
+			collection.seek_saturating(SeekFrom::Current(5)),
 			10,
-			"`End(0)` should move the cursor to one past the end of the collection",
+			"should stop one index past the last item"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::End(-1),
-			Some(9),
-			9,
-			"`End(-1)` should move the cursor to the end of the collection",
+		assert_eq!(collection.seek_saturating(SeekFrom::Start(usize::MAX)), 10);
+		assert_eq!(collection.seek_saturating(SeekFrom::End(isize::MAX)), 10);
+
+		assert_eq!(
+			collection.seek_saturating(SeekFrom::Current(-20)),
+			0,
+			"should stop at the beginning"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::End(-5),
-			Some(5),
-			5,
-			"`End(-x)` should move the cursor within the bounds of the collection",
+		collection.pos = 5;
+		assert_eq!(collection.seek_saturating(SeekFrom::End(isize::MIN)), 0);
+		assert_eq!(collection.pos, 0);
+	}
+
+	#[test]
+	fn seek_wrapping() {
+		let mut collection = self::test_collection();
+
+		assert_eq!(collection.seek_wrapping(SeekFrom::Start(3)), Some(3));
+		assert_eq!(
+			collection.seek_wrapping(SeekFrom::Start(13)),
+			Some(3),
+			"should wrap past the end"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::End(-10),
+		assert_eq!(collection.seek_wrapping(SeekFrom::Current(8)), Some(1));
+		assert_eq!(
+			collection.seek_wrapping(SeekFrom::Current(-3)),
+			Some(8),
+			"should wrap before the beginning"
+		);
+		assert_eq!(collection.seek_wrapping(SeekFrom::End(-1)), Some(9));
+		assert_eq!(
+			collection.seek_wrapping(SeekFrom::End(0)),
 			Some(0),
-			0,
-			"`End(-len)` should move the cursor to the start of the collection",
+			"should treat the end as the beginning"
+		);
+		collection.pos = 10;
+		assert_eq!(
+			collection.seek_wrapping(SeekFrom::Current(0)),
+			Some(10),
+			"`Current(0)` shouldn't move the cursor, even when one past the last item"
+		);
+		collection.pos = 0;
+		assert_eq!(
+			collection.seek_wrapping(SeekFrom::Current(isize::MIN)),
+			Some(2)
+		);
+		assert_eq!(
+			collection.seek_wrapping(SeekFrom::Current(isize::MAX)),
+			Some(9)
+		);
+
+		collection.pos = 9;
+		assert!(collection.seek_forward_one_wrapping());
+		assert_eq!(collection.pos, 0, "should wrap forwards to the beginning");
+		assert!(collection.seek_backward_one_wrapping());
+		assert_eq!(collection.pos, 9, "should wrap backwards to the last item");
+		assert!(collection.seek_backward_one());
+		assert_eq!(collection.pos, 8, "should mix with the strict seeks");
+
+		let mut empty = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(empty.seek_wrapping(SeekFrom::Current(1)), None);
+		assert!(
+			!empty.seek_forward_one_wrapping(),
+			"shouldn't move when empty"
+		);
+		assert!(
+			!empty.seek_backward_one_wrapping(),
+			"shouldn't move when empty"
 		);
+		assert_eq!(empty.pos, 0);
+	}
 
-		// Seek to a known position. We reuse the testing function to ensure we're actually there,
-		// just in case the test data has been messed with improperly.
-		inner(
-			&mut collection,
-			SeekFrom::Start(7),
-			Some(7),
-			7,
-			"this shouldn't fail",
+	#[test]
+	fn seek_policy() {
+		let mut collection = self::test_collection();
+		assert_eq!(
+			collection.seek_policy(),
+			SeekPolicy::Strict,
+			"should default to `Strict`"
 		);
 
-		// Seeking outside valid bounds should return `None` and *not* move the cursor
-		inner(
-			&mut collection,
-			SeekFrom::Start(usize::MAX),
-			None,
-			7,
-			"`Start(x)` shouldn't move if doing so would put it past one index past the end of the collection",
+		collection.set_seek_policy(SeekPolicy::Clamp);
+		collection.pos = 8;
+		assert_eq!(
+			collection.seek_relative(5),
+			Ok(10),
+			"`Clamp` should clamp to the end"
 		);
-
-		inner(
-			&mut collection,
-			SeekFrom::Current(-isize::MAX),
-			None,
-			7,
-			"`Current(-X)` shouldn't move if doing so would put it past the start of the collection",
+		assert!(
+			!collection.seek_forward_one(),
+			"`Clamp` shouldn't report a move at the end"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::Current(isize::MAX),
-			None,
-			7,
-			"`Current(x)` shouldn't move if doing so would put it past the end of the collection",
+		assert_eq!(collection.seek(SeekFrom::End(-20)), Ok(0));
+		assert!(!collection.seek_backward_one());
+		assert_eq!(collection.pos, 0);
+
+		collection.set_seek_policy(SeekPolicy::Wrap);
+		assert!(
+			collection.seek_backward_one(),
+			"`Wrap` should wrap backwards"
 		);
+		assert_eq!(collection.pos, 9);
+		assert!(collection.seek_forward_one(), "`Wrap` should wrap forwards");
+		assert_eq!(collection.pos, 0);
+		assert_eq!(collection.seek_relative(23), Ok(3));
 
-		inner(
-			&mut collection,
-			SeekFrom::End(1),
-			None,
-			7,
-			"`End(1)` shouldn't move if doing so would put it past the end of the collection",
+		assert!(
+			collection.shift_position_by(8).is_err(),
+			"`shift_position_by()` should always be strict"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::End(-isize::MAX),
-			None,
-			7,
-			"`End(-x)` shouldn't move if doing so would put it past the start of the collection",
+		assert!(
+			collection.batch_seek(&[SeekFrom::Current(-4)]).is_err(),
+			"`batch_seek()` should always be strict"
 		);
-		inner(
-			&mut collection,
-			SeekFrom::End(isize::MAX),
-			None,
-			7,
-			"`End(x)` shouldn't move if doing so would put it past the end of the collection",
+		assert_eq!(collection.pos, 3);
+
+		let mut empty = CollectionCursor::with_seek_policy(Vec::<i32>::new(), SeekPolicy::Wrap);
+		assert!(
+			empty.seek(SeekFrom::Current(1)).is_err(),
+			"`Wrap` should act as `Strict` when empty"
 		);
+		assert_eq!(empty.seek(SeekFrom::Start(0)), Ok(0));
 	}
 
-	macro_rules! __clamp_to {
-		($method:tt, $first_test_expected_pos:expr, $first_test_error_message:expr) => {
-			let mut collection = self::test_collection();
+	#[test]
+	fn advance_by_and_retreat_by() {
+		let mut collection = self::test_collection();
 
-			collection.pos = usize::MAX;
-			collection.$method();
-			assert_eq!(
-				collection.pos, $first_test_expected_pos,
-				$first_test_error_message
-			);
+		assert_eq!(collection.advance_by(4), Ok(()));
+		assert_eq!(collection.pos, 4);
+		assert_eq!(
+			collection.advance_by(9),
+			Err(NonZeroUsize::new(3).unwrap()),
+			"should report the steps which couldn't be taken"
+		);
+		assert_eq!(collection.pos, 10, "should move as far as it can");
+		assert_eq!(collection.advance_by(0), Ok(()));
+		assert_eq!(collection.advance_by(1), Err(NonZeroUsize::MIN));
 
-			collection.pos = 2;
-			collection.$method();
-			assert_eq!(
-				collection.pos, 2,
-				"shouldn't move the cursor when already within the bounds of the collection"
-			);
+		assert_eq!(collection.retreat_by(6), Ok(()));
+		assert_eq!(collection.pos, 4);
+		assert_eq!(
+			collection.retreat_by(usize::MAX),
+			Err(NonZeroUsize::new(usize::MAX - 4).unwrap()),
+			"should report the steps which couldn't be taken"
+		);
+		assert_eq!(collection.pos, 0, "should move as far as it can");
+	}
 
-			collection = CollectionCursor::new(Vec::from([]));
+	#[test]
+	fn iter_remaining() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
 
-			collection.pos = 0;
-			collection.$method();
-			assert_eq!(
-				collection.pos, 0,
-				"should keep the cursor at index `0` when given an empty collection"
-			);
+		let iter = collection.iter_remaining();
+		assert_eq!(iter.len(), 4);
+		assert_eq!(
+			iter.copied().collect::<Vec<_>>(),
+			[9, 8, 7, 6],
+			"should yield the items from the cursor onwards"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
 
-			collection.pos = usize::MAX;
-			collection.$method();
-			assert_eq!(
-				collection.pos, 0,
-				"should keep the cursor at index `0` when given an empty collection"
-			);
-		};
+		collection.pos = 10;
+		assert_eq!(
+			collection.iter_remaining().next(),
+			None,
+			"should yield nothing at the end"
+		);
+
+		let mut deque = CollectionCursor::new(VecDeque::from([1, 2, 3]));
+		deque.push_front(0);
+		deque.seek_forward_one();
+		assert_eq!(
+			deque.iter_remaining().copied().collect::<Vec<_>>(),
+			[2, 3],
+			"should work with non-contiguous collections"
+		);
 	}
 
 	#[test]
-	fn clamp_to_last_item() {
-		let collection_len = self::test_collection().inner.len();
+	fn iter_remaining_mut() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
 
-		__clamp_to!(
-			clamp_to_last_item,
-			collection_len - 1,
-			"should move the cursor to the last item of the collection"
+		let mut iter = collection.iter_remaining_mut();
+		assert_eq!(iter.len(), 4);
+		while let Some(item) = iter.next() {
+			*item += 10;
+		}
+		assert!(iter.is_empty());
+		assert_eq!(
+			collection.inner,
+			[0, 1, 2, 3, 4, 5, 19, 18, 17, 16],
+			"should only modify the items from the cursor onwards"
 		);
-	}
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
 
-	#[test]
-	fn clamp_to_end() {
-		let collection_len = self::test_collection().inner.len();
+		collection.pos = 8;
+		collection.iter_remaining_mut().for_each(|item| *item = 0);
+		assert_eq!(collection.inner, [0, 1, 2, 3, 4, 5, 19, 18, 0, 0]);
 
-		__clamp_to!(
-			clamp_to_end,
-			collection_len,
-			"should move the cursor to the one index past the last item of the collection"
+		collection.pos = 10;
+		assert!(
+			collection.iter_remaining_mut().next().is_none(),
+			"should yield nothing at the end"
 		);
 	}
 
 	#[test]
-	fn seek_backward_one() {
-		fn inner(
-			collection: &mut TestCollection,
-			should_succeed: bool,
-			expected_new_pos: usize,
-			error_message: &'static str,
-		) {
-			let seek_success = collection.seek_backward_one();
-			assert_eq!(seek_success, should_succeed, "{error_message}");
-			assert_eq!(
-				collection.pos, expected_new_pos,
-				"cursor was not moved to the expected position"
-			);
-		}
-
+	fn into_iterator() {
 		let mut collection = self::test_collection();
+		collection.pos = 7;
 
-		inner(
-			&mut collection,
-			false,
-			0,
-			"shouldn't seek past the beginning of the collection",
+		let mut sum = 0;
+		for item in &collection {
+			sum += item;
+		}
+		assert_eq!(
+			sum,
+			8 + 7 + 6,
+			"should iterate by reference from the cursor"
 		);
 
-		collection.pos = 5;
-		inner(
-			&mut collection,
-			true,
-			4,
-			"should seek backward if within the bounds of the collection",
+		for item in &mut collection {
+			*item *= 2;
+		}
+		assert_eq!(
+			collection.inner,
+			[0, 1, 2, 3, 4, 5, 9, 16, 14, 12],
+			"should iterate mutably from the cursor"
 		);
+		assert_eq!(collection.pos, 7, "shouldn't move the cursor");
 
-		collection.pos = usize::MAX;
-		inner(
-			&mut collection,
-			false,
-			usize::MAX,
-			"shouldn't seek if outside the bounds of the collection",
+		assert_eq!(
+			collection.into_iter().collect::<Vec<_>>(),
+			[16, 14, 12],
+			"should consume the cursor, yielding the items from the cursor"
+		);
+
+		let deque = CollectionCursor::from_parts(VecDeque::from([1, 2, 3]), 3).unwrap();
+		assert_eq!(
+			deque.into_iter().count(),
+			0,
+			"should yield nothing at the end"
 		);
 	}
 
 	#[test]
-	fn seek_relative() {
-		fn inner(
-			collection: &mut TestCollection,
-			offset: isize,
-			expected_result: Option<usize>,
-			expected_pos: usize,
-			error_message: &'static str,
-		) {
-			let seek_res = collection.seek_relative(offset);
-			assert_eq!(seek_res, expected_result, "{error_message}");
-			assert_eq!(
-				collection.pos, expected_pos,
-				"the relative seek did not position the cursor as expected"
-			);
-		}
-
+	fn iter_consumed() {
 		let mut collection = self::test_collection();
+		collection.pos = 3;
 
-		inner(&mut collection, 0, Some(0), 0, "shouldn't move at all");
-
-		collection.pos = 5;
-		inner(
-			&mut collection,
-			-2,
-			Some(3),
-			3,
-			"should move when within the bounds of the collection",
+		assert_eq!(
+			collection.iter_consumed().copied().collect::<Vec<_>>(),
+			[0, 1, 2],
+			"should yield the items before the cursor"
 		);
-		inner(
-			&mut collection,
-			2,
-			Some(5),
-			5,
-			"should move when within the bounds of the collection",
+		assert_eq!(
+			collection
+				.iter_consumed()
+				.rev()
+				.copied()
+				.collect::<Vec<_>>(),
+			[2, 1, 0],
+			"should be reversible"
 		);
+		assert_eq!(collection.pos, 3, "shouldn't move the cursor");
 
-		inner(&mut collection, 0, Some(5), 5, "shouldn't move at all");
+		collection.pos = 0;
+		assert_eq!(collection.iter_consumed().next(), None);
+	}
 
-		collection.pos = 5;
-		inner(
-			&mut collection,
-			isize::MAX,
-			None,
-			5,
-			"shouldn't move past the end of the collection",
+	#[test]
+	fn iter_double_ended() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		assert_eq!(
+			collection
+				.iter_remaining()
+				.rev()
+				.copied()
+				.collect::<Vec<_>>(),
+			[6, 7, 8, 9],
+			"should be reversible"
 		);
-		inner(
-			&mut collection,
-			-isize::MAX,
+
+		let mut iter = collection.iter_remaining();
+		assert_eq!(iter.next(), Some(&9));
+		assert_eq!(iter.next_back(), Some(&6));
+		assert_eq!(iter.len(), 2);
+		assert_eq!(iter.next_back(), Some(&7));
+		assert_eq!(iter.next(), Some(&8));
+		assert_eq!(iter.next(), None, "should stop where both ends meet");
+		assert_eq!(iter.next_back(), None, "should stop where both ends meet");
+
+		let mut iter = collection.iter_remaining_mut();
+		*iter.next_back().unwrap() = 60;
+		*iter.next().unwrap() = 90;
+		assert_eq!(iter.len(), 2);
+		assert_eq!(collection.inner[6..], [90, 8, 7, 60]);
+	}
+
+	#[test]
+	fn next_item_and_prev_item() {
+		let mut collection = self::test_collection();
+		collection.pos = 8;
+
+		assert_eq!(
+			collection.next_item(),
+			Some(&7),
+			"should read the item at the cursor"
+		);
+		assert_eq!(collection.pos, 9, "should advance after reading");
+		assert_eq!(collection.next_item(), Some(&6));
+		assert_eq!(
+			collection.next_item(),
 			None,
-			5,
-			"shouldn't move before the beginning of the collection",
+			"should return `None` at the end"
 		);
+		assert_eq!(collection.pos, 10, "shouldn't move past the end");
 
-		collection.pos = usize::MAX;
-		inner(
-			&mut collection,
-			5,
-			None,
-			usize::MAX,
-			"shouldn't move when outside the bounds of the collection",
+		assert_eq!(
+			collection.prev_item(),
+			Some(&6),
+			"should retreat before reading"
 		);
-		inner(
-			&mut collection,
-			-5,
+		assert_eq!(collection.pos, 9);
+
+		collection.pos = 1;
+		assert_eq!(collection.prev_item(), Some(&0));
+		assert_eq!(
+			collection.prev_item(),
 			None,
-			usize::MAX,
-			"shouldn't move when outside the bounds of the collection",
+			"should return `None` at the beginning"
 		);
-	}
-
-	#[test]
-	fn seek_forward_one() {
-		fn inner(
-			collection: &mut TestCollection,
-			should_succeed: bool,
-			expected_new_pos: usize,
-			error_message: &'static str,
-		) {
-			let seek_success = collection.seek_forward_one();
-			assert_eq!(seek_success, should_succeed, "{error_message}");
-			assert_eq!(
-				collection.pos, expected_new_pos,
-				"cursor was not moved to the expected position"
-			);
-		}
+		assert_eq!(collection.pos, 0);
+	}
 
+	#[test]
+	fn peek() {
 		let mut collection = self::test_collection();
+		collection.pos = 5;
 
-		inner(
-			&mut collection,
-			true,
-			1,
-			"should seek forward when an item is available",
+		assert_eq!(collection.peek(), Some(&5));
+		assert_eq!(collection.peek_offset(0), Some(&5));
+		assert_eq!(collection.peek_offset(2), Some(&8), "should look ahead");
+		assert_eq!(collection.peek_offset(-5), Some(&0), "should look behind");
+		assert_eq!(
+			collection.peek_offset(5),
+			None,
+			"should return `None` past the last item"
 		);
-
-		collection.pos = 5;
-		inner(
-			&mut collection,
-			true,
-			6,
-			"should seek forward when an item is available",
+		assert_eq!(
+			collection.peek_offset(-6),
+			None,
+			"should return `None` before the beginning"
 		);
+		assert_eq!(collection.peek_offset(isize::MAX), None);
+		assert_eq!(collection.pos, 5, "shouldn't move the cursor");
 
-		let collection_len = collection.inner.len();
+		collection.pos = 10;
+		assert_eq!(collection.peek(), None);
+		assert_eq!(collection.peek_offset(-1), Some(&6));
+	}
 
-		collection.pos = collection_len - 1;
-		inner(
-			&mut collection,
-			true,
-			collection_len,
-			"should seek one past the end of the bounds of the collection",
+	#[test]
+	fn neighbors() {
+		let mut collection = self::test_collection();
+		collection.pos = 5;
+		assert_eq!(collection.neighbors(), (Some(&4), Some(&5), Some(&9)));
+
+		collection.pos = 0;
+		assert_eq!(
+			collection.neighbors(),
+			(None, Some(&0), Some(&1)),
+			"should have no previous item at the beginning"
 		);
 
-		collection.pos = collection_len;
-		inner(
-			&mut collection,
-			false,
-			collection_len,
-			"shouldn't seek past one index past the end of the bounds of the collection",
+		collection.pos = 9;
+		assert_eq!(
+			collection.neighbors(),
+			(Some(&7), Some(&6), None),
+			"should have no next item at the last item"
 		);
 
-		collection.pos = usize::MAX;
-		inner(
-			&mut collection,
-			false,
-			usize::MAX,
-			"shouldn't seek at all past `len()` of the collection",
+		collection.pos = 10;
+		assert_eq!(
+			collection.neighbors(),
+			(Some(&6), None, None),
+			"should only have a previous item at the end"
 		);
+
+		let empty = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(empty.neighbors(), (None, None, None));
 	}
 
-	macro_rules! __seek_to {
-		($method:tt, $initial_pos:expr, $expected_pos:expr) => {
-			let mut collection = self::test_collection();
-			collection.pos = $initial_pos;
-			collection.$method();
-			assert_eq!(collection.pos, $expected_pos);
-		};
+	#[test]
+	fn read_items() {
+		let mut collection = self::test_collection();
+		collection.pos = 4;
+
+		let mut buf = [0; 3];
+		assert_eq!(collection.read_items(&mut buf), 3);
+		assert_eq!(buf, [4, 5, 9], "should copy the items from the cursor");
+		assert_eq!(collection.pos, 7, "should advance past the copied items");
+
+		let mut buf = [-1; 5];
+		assert_eq!(
+			collection.read_items(&mut buf),
+			3,
+			"should only copy the remaining items"
+		);
+		assert_eq!(
+			buf,
+			[8, 7, 6, -1, -1],
+			"shouldn't touch the rest of the buffer"
+		);
+		assert_eq!(collection.pos, 10);
+
+		assert_eq!(collection.read_items(&mut buf), 0);
 	}
 
 	#[test]
-	fn seek_to_start() {
-		// seek_to_start should ALWAYS succeed
-		__seek_to!(seek_to_start, 5, 0);
-		__seek_to!(seek_to_start, usize::MAX, 0);
+	fn read_exact_items() {
+		let mut collection = self::test_collection();
+		collection.pos = 6;
+
+		let mut buf = [0; 2];
+		assert_eq!(collection.read_exact_items(&mut buf), Ok(()));
+		assert_eq!(buf, [9, 8]);
+		assert_eq!(collection.pos, 8);
+
+		let mut buf = [-1; 3];
+		let error = collection
+			.read_exact_items(&mut buf)
+			.expect_err("only two items remain");
+		assert_eq!(error.requested(), 3);
+		assert_eq!(error.available(), 2);
+		assert_eq!(buf, [-1; 3], "shouldn't touch the buffer when failing");
+		assert_eq!(collection.pos, 8, "shouldn't move the cursor when failing");
+
+		assert_eq!(
+			collection.read_exact_items(&mut []),
+			Ok(()),
+			"should always succeed with an empty buffer"
+		);
 	}
 
 	#[test]
-	fn seek_to_last_item() {
-		let expected_pos = self::test_vec().len() - 1;
-		__seek_to!(seek_to_last_item, 5, expected_pos);
-		__seek_to!(seek_to_last_item, usize::MAX, expected_pos);
+	fn write_items() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(collection.write_items(&[20, 30]), 2);
+		assert_eq!(collection.inner, [0, 1, 20, 30, 4, 5, 9, 8, 7, 6]);
+		assert_eq!(collection.pos, 4, "should advance past the written slots");
+
+		collection.pos = 8;
+		assert_eq!(
+			collection.write_items(&[70, 60, 50]),
+			2,
+			"should stop writing at the end of the collection"
+		);
+		assert_eq!(collection.inner, [0, 1, 20, 30, 4, 5, 9, 8, 70, 60]);
+		assert_eq!(collection.pos, 10);
+
+		assert_eq!(
+			collection.write_items(&[1]),
+			0,
+			"shouldn't write at the end"
+		);
+		assert_eq!(collection.inner.len(), 10, "shouldn't grow the collection");
 	}
 
 	#[test]
-	fn seek_to_end() {
-		// seek_to_end should ALWAYS succeed
-		let expected_pos = self::test_collection().inner.len();
-		__seek_to!(seek_to_end, 5, expected_pos);
-		__seek_to!(seek_to_end, usize::MAX, expected_pos);
+	fn read_until_span() {
+		let mut collection = self::test_collection();
+		collection.pos = 2;
+
+		assert_eq!(
+			collection.read_until_span(&5),
+			2..6,
+			"should include the delimiter in the range"
+		);
+		assert_eq!(collection.pos, 6, "should move past the delimiter");
+
+		assert_eq!(
+			collection.read_until_span(&9),
+			6..7,
+			"should match the item at the cursor"
+		);
+		assert_eq!(
+			collection.read_until_span(&0),
+			7..10,
+			"should consume everything if the delimiter isn't found"
+		);
+		assert_eq!(collection.read_until_span(&0), 10..10);
+
+		collection.pos = 12;
+		assert_eq!(
+			collection.read_until_span(&0),
+			10..10,
+			"shouldn't underflow when the cursor is past the end"
+		);
 	}
 
 	#[test]
-	fn get_item_at_cursor() {
-		let test_vec = self::test_vec();
+	fn remaining_and_consumed_slices() {
 		let mut collection = self::test_collection();
+		collection.pos = 7;
 
-		// We deliberately request one item past the end, to test if that's also the same
-		for i in 0..=(test_vec.len()) {
-			collection.pos = i;
-			let test_vec_get = test_vec.get(i);
-			let collection_get = collection.get_item_at_cursor();
+		assert_eq!(collection.remaining_slice(), [8, 7, 6]);
+		assert_eq!(collection.consumed_slice(), [0, 1, 2, 3, 4, 5, 9]);
 
-			assert_eq!(
-				test_vec_get, collection_get,
-				"should get the same item from the same index (index = `{i}`)"
-			);
-		}
+		collection.remaining_slice_mut()[0] = 80;
+		collection.consumed_slice_mut()[6] = 90;
+		assert_eq!(collection.inner, [0, 1, 2, 3, 4, 5, 90, 80, 7, 6]);
+		assert_eq!(collection.pos, 7, "shouldn't move the cursor");
+
+		collection.seek_to_end();
+		assert!(
+			collection.remaining_slice().is_empty(),
+			"should be empty at the end"
+		);
+		assert_eq!(collection.consumed_slice().len(), 10);
+
+		collection.seek_to_start();
+		assert!(
+			collection.consumed_slice().is_empty(),
+			"should be empty at the start"
+		);
+		assert_eq!(collection.remaining_slice_mut().len(), 10);
 	}
 
 	#[test]
-	fn clear() {
-		let mut test_vec = self::test_vec();
+	fn split_at_cursor() {
 		let mut collection = self::test_collection();
+		collection.pos = 6;
 
-		test_vec.clear();
-		collection.clear();
+		let (consumed, remaining) = collection.split_at_cursor();
+		assert_eq!(consumed, [0, 1, 2, 3, 4, 5]);
+		assert_eq!(remaining, [9, 8, 7, 6]);
 
-		assert_eq!(collection.inner, test_vec);
+		// Copy a back-reference from the consumed half into the remaining half
+		remaining[..3].copy_from_slice(&consumed[1..4]);
+		assert_eq!(collection.inner, [0, 1, 2, 3, 4, 5, 1, 2, 3, 6]);
+		assert_eq!(collection.pos, 6, "shouldn't move the cursor");
+
+		collection.seek_to_end();
+		let (consumed, remaining) = collection.split_at_cursor();
+		assert_eq!(consumed.len(), 10);
+		assert!(remaining.is_empty(), "should be empty at the end");
 	}
 
 	#[test]
-	fn get_item_at_cursor_mut() {
-		let mut test_vec = self::test_vec();
+	fn seek_to_next() {
 		let mut collection = self::test_collection();
+		collection.pos = 2;
 
-		// We deliberately request one item past the end, to test if that's also the same
-		for i in 0..=(test_vec.len()) {
-			collection.pos = i;
-			let test_vec_get = test_vec.get_mut(i);
-			let collection_get = collection.get_item_at_cursor_mut();
+		assert_eq!(collection.seek_to_next(&9), Some(6));
+		assert_eq!(collection.pos, 6);
+		assert_eq!(
+			collection.seek_to_next(&9),
+			Some(6),
+			"should match the item at the cursor"
+		);
+		assert_eq!(
+			collection.seek_to_next(&1),
+			None,
+			"shouldn't search before the cursor"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move when not found");
 
-			assert_eq!(
-				test_vec_get, collection_get,
-				"should get the same item from the same index (index = `{i}`)"
-			);
+		let mut collection = CollectionCursor::new(VecDeque::from(self::test_vec()));
+		assert_eq!(collection.seek_to_next(&7), Some(8));
+
+		let mut collection = CollectionCursor::new(VecDeque::from([1, 2, 1, 3, 1]));
+		assert_eq!(collection.seek_to_next(&1), Some(0));
+		assert_eq!(
+			collection.seek_to_next(&1),
+			Some(0),
+			"repeated calls shouldn't advance past the match"
+		);
+		let mut found = Vec::new();
+		while let Some(pos) = collection.seek_to_next(&1) {
+			found.push(pos);
+			collection.seek_forward_one();
 		}
+		assert_eq!(
+			found,
+			[0, 2, 4],
+			"should find each match when stepping past the previous one"
+		);
 	}
 
-	fn __set_item(mut collection: TestCollection, mut test_vec: TestVec) {
-		const AT_POS: usize = 5;
-		const TO_VALUE: i32 = 52345;
+	#[test]
+	fn seek_to_prev() {
+		let mut collection = CollectionCursor::new(VecDeque::from([1, 2, 1, 3, 1]));
+		collection.pos = 4;
 
-		test_vec[AT_POS] = TO_VALUE;
-		collection.pos = AT_POS;
-		collection.set_item_at_cursor(TO_VALUE);
+		assert_eq!(
+			collection.seek_to_prev(&1),
+			Some(2),
+			"shouldn't match the item at the cursor"
+		);
+		assert_eq!(collection.seek_to_prev(&1), Some(0));
+		assert_eq!(collection.seek_to_prev(&1), None);
+		assert_eq!(collection.pos, 0, "shouldn't move when not found");
 
+		collection.pos = 5;
 		assert_eq!(
-			collection.inner.get(AT_POS),
-			Some(&TO_VALUE),
-			"should modify the inner collection to have the correct value at the head"
+			collection.seek_to_prev(&1),
+			Some(4),
+			"should search from the last item when at the end"
+		);
+		assert_eq!(
+			collection.seek_to_prev(&3),
+			Some(3),
+			"should match the item just before the cursor"
 		);
-		assert_eq!(collection.inner, test_vec, "should set only one value");
 	}
 
 	#[test]
-	fn set_item_at_cursor() {
-		let test_vec = self::test_vec();
-		let collection = self::test_collection();
+	#[cfg(feature = "core")]
+	fn skip_while() {
+		let mut collection = CollectionCursor::new(*b"   \tword  ");
 
-		self::__set_item(collection, test_vec);
+		assert_eq!(collection.skip_while(u8::is_ascii_whitespace), 4);
+		assert_eq!(collection.pos, 4);
+		assert_eq!(
+			collection.skip_while(u8::is_ascii_whitespace),
+			0,
+			"shouldn't move when the item at the cursor doesn't match"
+		);
+		assert_eq!(collection.skip_while(u8::is_ascii_alphabetic), 4);
+		assert_eq!(collection.skip_while(u8::is_ascii_whitespace), 2);
+		assert!(collection.is_cursor_at_end());
 	}
 
-	fn __insert_item(mut collection: TestCollection, mut test_vec: TestVec) {
-		const AT_POS: usize = 5;
-		const TO_VALUE: i32 = 52345;
+	#[test]
+	fn seek_to_match() {
+		let mut collection = self::test_collection();
+		collection.pos = 3;
 
-		test_vec.insert(AT_POS, TO_VALUE);
-		collection.pos = AT_POS;
-		collection.insert_item_at_cursor(TO_VALUE);
+		assert_eq!(collection.seek_to_match(|&item| item > 5), Some(6));
+		assert_eq!(
+			collection.seek_to_match(|&item| item > 5),
+			Some(6),
+			"should match the item at the cursor"
+		);
+		assert_eq!(
+			collection.seek_to_match(|&item| item < 5),
+			None,
+			"shouldn't search before the cursor"
+		);
+		assert_eq!(collection.pos, 6, "shouldn't move when not found");
+	}
+
+	#[test]
+	fn seek_binary_search() {
+		let mut collection = CollectionCursor::new(VecDeque::from([1, 3, 5, 7, 9]));
 
+		assert_eq!(collection.seek_binary_search(&7), Ok(3));
+		assert_eq!(collection.pos, 3);
 		assert_eq!(
-			collection.inner.get(AT_POS),
-			Some(&TO_VALUE),
-			"should modify the inner collection to have the correct value at the head"
+			collection.seek_binary_search(&4),
+			Err(2),
+			"should move to the insertion point if not found"
 		);
-		assert_eq!(collection.inner, test_vec, "should insert only one value");
+		assert_eq!(collection.pos, 2);
+		assert_eq!(collection.seek_binary_search(&10), Err(5));
+		assert_eq!(collection.pos, 5);
+		assert_eq!(collection.seek_binary_search(&0), Err(0));
+
+		assert_eq!(
+			collection.seek_binary_search_by_key(&18, |&item| item * 2),
+			Ok(4)
+		);
+		assert_eq!(collection.seek_binary_search_by(|item| item.cmp(&1)), Ok(0));
+
+		let mut collection = CollectionCursor::new(Vec::<i32>::new());
+		assert_eq!(collection.seek_binary_search(&1), Err(0));
 	}
 
 	#[test]
-	fn insert_item_at_cursor() {
-		let test_vec = self::test_vec();
-		let collection = self::test_collection();
+	fn seek_partition_point() {
+		let mut collection = CollectionCursor::new(VecDeque::from([10, 20, 20, 30, 40]));
 
-		self::__insert_item(collection, test_vec);
+		assert_eq!(collection.seek_partition_point(|&time| time <= 20), 3);
+		assert_eq!(collection.pos, 3);
+		assert_eq!(collection.seek_partition_point(|&time| time < 20), 1);
+		assert_eq!(
+			collection.seek_partition_point(|&time| time < 100),
+			5,
+			"should move to the end if every item matches"
+		);
+		assert_eq!(
+			collection.seek_partition_point(|&time| time < 0),
+			0,
+			"should move to the start if no item matches"
+		);
 	}
 
 	#[test]
-	fn set_or_insert_item_at_cursor() {
-		let test_vec = self::test_vec();
-		let collection = self::test_collection();
+	#[cfg(feature = "core")]
+	fn starts_with_and_consume_prefix() {
+		let mut collection = CollectionCursor::new(*b"\x89PNG\r\n");
 
-		self::__set_item(collection.clone(), test_vec.clone());
-		self::__insert_item(collection.clone(), test_vec.clone());
+		assert!(collection.starts_with(b"\x89PNG"));
+		assert!(
+			collection.starts_with(b""),
+			"should always match an empty prefix"
+		);
+		assert!(!collection.starts_with(b"GIF"));
+		assert!(
+			!collection.starts_with(b"\x89PNG\r\n\x1a"),
+			"shouldn't match a prefix longer than the remaining items"
+		);
+		assert_eq!(collection.pos, 0, "shouldn't move the cursor");
+
+		assert!(!collection.consume_prefix(b"GIF"));
+		assert_eq!(
+			collection.pos, 0,
+			"shouldn't move when the prefix doesn't match"
+		);
+		assert!(collection.consume_prefix(b"\x89PNG"));
+		assert_eq!(collection.pos, 4);
+		assert!(collection.consume_prefix(b"\r\n"));
+		assert!(collection.is_cursor_at_end());
 	}
 
 	#[test]
-	fn remove_item_at_cursor() {
-		const AT_POS: usize = 5;
+	fn expect_item() {
+		let mut collection = CollectionCursor::new(VecDeque::from(['(', 'a', ')']));
 
-		let mut test_vec = self::test_vec();
+		assert_eq!(collection.expect_item(&'('), Ok(()));
+		assert_eq!(collection.pos, 1, "should move past the expected item");
+
+		let error = collection.expect_item(&')').unwrap_err();
+		assert_eq!(error.position(), 1);
+		assert_eq!(error.into_found(), Some(&'a'));
+		assert_eq!(collection.pos, 1, "shouldn't move on an unexpected item");
+
+		let error = collection.expect_item_cloned(&')').unwrap_err();
+		assert_eq!(
+			error.into_found(),
+			Some('a'),
+			"should hold a clone of the item found"
+		);
+
+		collection.pos = 3;
+		let error = collection.expect_item(&')').unwrap_err();
+		assert_eq!(error.position(), 3);
+		assert_eq!(error.found(), None, "shouldn't find an item at the end");
+
+		/// An item which can be compared, but not cloned.
+		#[derive(Debug, PartialEq)]
+		struct Token(char);
+
+		let mut collection = CollectionCursor::new(Vec::from([Token('('), Token(')')]));
+		assert_eq!(collection.expect_item(&Token('(')), Ok(()));
+		let error = collection.expect_item(&Token('(')).unwrap_err();
+		assert_eq!(
+			error.into_found(),
+			Some(&Token(')')),
+			"should work without `Clone`"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn try_parse() {
+		let mut collection = CollectionCursor::new(*b"let x");
+
+		let result: Result<(), ()> = collection.try_parse(|cursor| {
+			cursor.consume_prefix(b"le").then_some(()).ok_or(())?;
+			cursor.consume_prefix(b"x").then_some(()).ok_or(())
+		});
+		assert_eq!(result, Err(()));
+		assert_eq!(collection.pos, 0, "should roll back on an error");
+
+		let result: Result<usize, ()> = collection.try_parse(|cursor| {
+			cursor.consume_prefix(b"let ").then_some(()).ok_or(())?;
+			Ok(cursor.position())
+		});
+		assert_eq!(result, Ok(4));
+		assert_eq!(collection.pos, 4, "shouldn't roll back on success");
+	}
+
+	#[test]
+	fn checkpoint_follows_items() {
 		let mut collection = self::test_collection();
+		collection.pos = 4;
+		collection.save_checkpoint();
 
-		let test_vec_res = test_vec.remove(5);
-		collection.pos = AT_POS;
-		let collection_res = collection.remove_item_at_cursor();
+		collection.pos = 1;
+		collection.insert_item_at_cursor(10);
 		assert_eq!(
-			collection_res,
-			Some(test_vec_res),
-			"should return the right value"
+			collection.checkpoint(),
+			Some(5),
+			"should shift after an insert"
 		);
-		assert_eq!(collection.inner, test_vec, "should remove only one value");
 
-		// Additionally, test that this does NOT panic when out-of-bounds
-		collection.pos = collection.inner.len() * 2;
-		let collection_res = collection.remove_item_at_cursor();
+		collection.insert_slice_at_cursor(&[11, 12]);
+		assert_eq!(collection.checkpoint(), Some(7));
+
+		collection.pos = 7;
+		assert!(collection.transpose_at_cursor());
 		assert_eq!(
-			collection_res, None,
-			"should return `None` if the head was out-of-bounds"
+			collection.checkpoint(),
+			Some(6),
+			"should follow a transposed item"
 		);
+
+		collection.pos = 0;
+		collection.collect_remaining::<Vec<_>>();
+		assert_eq!(collection.checkpoint(), Some(0));
 	}
 }
@@ -0,0 +1,174 @@
+extern crate alloc;
+
+use alloc::string::String;
+
+use crate::{CollectionCursor, IndexableCollection};
+
+impl<Tape> CollectionCursor<Tape> {
+	/// Saves the cursor's current position as a mark named `name`, replacing any existing mark
+	/// with that name.
+	///
+	/// Marks are separate from the checkpoints saved by [`Self::save_checkpoint()`] and
+	/// [`Self::push_checkpoint()`].
+	///
+	/// Like the checkpoints, marks are shifted when items are inserted or removed through the
+	/// cursor's own methods, so that they keep pointing at the same items.
+	pub fn set_mark(&mut self, name: &str) {
+		#[cfg(feature = "debug-invariants")]
+		self.before_saving_position();
+
+		match self.marks.get_mut(name) {
+			Some(mark) => *mark = self.pos,
+			None => {
+				self.marks.insert(String::from(name), self.pos);
+			}
+		}
+	}
+
+	/// Returns the position saved in the mark named `name`, or `None` if no such mark exists.
+	pub fn mark(&self, name: &str) -> Option<usize> {
+		self.marks.get(name).copied()
+	}
+
+	/// Removes the mark named `name`.
+	///
+	/// Returns the position saved in the mark, or `None` if no such mark exists.
+	pub fn clear_mark(&mut self, name: &str) -> Option<usize> {
+		self.marks.remove(name)
+	}
+
+	/// Removes every mark.
+	pub fn clear_marks(&mut self) {
+		self.marks.clear();
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape> {
+	/// Moves the cursor to the position saved in the mark named `name`. If the collection has
+	/// shrunk since then, the cursor is instead moved to one index past the last item. The mark is
+	/// kept, so it may be returned to again later.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the new position of the cursor, or `None` if no
+	/// such mark exists. In the latter case, the cursor will not be moved.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if the collection was edited outside of the
+	/// cursor since the position was saved (such as through [`Self::get_mut()`]), and the position
+	/// is still within the bounds of the collection, as it may no longer point at the same item.
+	/// A position past the end of the collection is clamped as described above instead.
+	pub fn seek_to_mark(&mut self, name: &str) -> Option<usize> {
+		let mark = self.mark(name)?;
+		#[cfg(feature = "debug-invariants")]
+		self.assert_saved_position_valid(mark);
+
+		self.pos = mark.min(self.inner.len());
+		Some(self.pos)
+	}
+}
+
+#[cfg(test)]
+mod marks_tests {
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use crate::CollectionCursor;
+
+	#[test]
+	fn marks() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+
+		cursor.set_mark("start");
+		cursor.seek_relative(3).unwrap();
+		cursor.set_mark("middle");
+		cursor.seek_to_end();
+
+		assert_eq!(cursor.mark("start"), Some(0));
+		assert_eq!(cursor.mark("middle"), Some(3));
+		assert_eq!(cursor.mark("end"), None);
+
+		assert_eq!(cursor.seek_to_mark("middle"), Some(3));
+		assert_eq!(cursor.position(), 3);
+		assert_eq!(cursor.seek_to_mark("end"), None);
+		assert_eq!(cursor.position(), 3, "shouldn't move to a missing mark");
+
+		cursor.seek_relative(1).unwrap();
+		cursor.set_mark("start");
+		assert_eq!(
+			cursor.mark("start"),
+			Some(4),
+			"should replace an existing mark"
+		);
+
+		assert_eq!(cursor.clear_mark("start"), Some(4));
+		assert_eq!(cursor.mark("start"), None);
+		cursor.clear_marks();
+		assert_eq!(cursor.mark("middle"), None);
+	}
+
+	#[test]
+	fn seek_to_mark_clamps() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_to_end();
+		cursor.set_mark("end");
+
+		cursor.get_mut_guarded().truncate(2);
+		assert_eq!(
+			cursor.seek_to_mark("end"),
+			Some(2),
+			"should clamp to the end of a shrunken collection"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "debug-invariants")]
+	#[should_panic = "edited outside of the cursor"]
+	fn seek_to_mark_after_untracked_edit() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_relative(1).unwrap();
+		cursor.set_mark("one");
+
+		cursor.get_mut_guarded().truncate(2);
+		cursor.seek_to_mark("one");
+	}
+
+	#[test]
+	fn marks_follow_items() {
+		let mut cursor = CollectionCursor::new(VecDeque::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_relative(2).unwrap();
+		cursor.set_mark("two");
+		cursor.seek_relative(2).unwrap();
+		cursor.set_mark("four");
+		cursor.push_checkpoint();
+
+		cursor.seek_relative(-3).unwrap();
+		cursor.insert_item_at_cursor(10);
+		assert_eq!(cursor.mark("two"), Some(3), "should shift after an insert");
+		assert_eq!(cursor.mark("four"), Some(5));
+
+		cursor.remove_item_at_cursor();
+		assert_eq!(cursor.mark("two"), Some(2), "should shift after a removal");
+		assert_eq!(cursor.mark("four"), Some(4));
+
+		cursor.push_front(20);
+		assert_eq!(cursor.mark("two"), Some(3));
+		cursor.pop_front();
+		assert_eq!(cursor.mark("two"), Some(2));
+
+		assert!(cursor.move_item(4, 0));
+		assert_eq!(cursor.mark("two"), Some(3), "should follow a moved item");
+		assert_eq!(cursor.mark("four"), Some(0));
+		assert_eq!(cursor.rollback_to_checkpoint(), Some(0));
+		assert_eq!(cursor.get_item_at_cursor(), Some(&4));
+
+		cursor.seek_relative(3).unwrap();
+		cursor.remove_item_at_cursor();
+		assert_eq!(
+			cursor.mark("two"),
+			Some(3),
+			"should point at the following item once its item is removed"
+		);
+
+		cursor.clear();
+		assert_eq!(cursor.mark("two"), Some(0));
+		assert_eq!(cursor.mark("four"), Some(0));
+	}
+}
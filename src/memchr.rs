@@ -0,0 +1,128 @@
+use crate::{CollectionCursor, ContiguousCollection};
+
+impl<Tape: ContiguousCollection<Item = u8>> CollectionCursor<Tape> {
+	/// Searches forwards from the cursor for the first byte equal to `byte`, and moves the cursor
+	/// to it. The byte pointed at by the cursor is included in the search.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the matching byte, or `None` if no
+	/// byte matches. In the latter case, the cursor will not be moved.
+	pub fn seek_to_byte(&mut self, byte: u8) -> Option<usize> {
+		self.record_seek();
+		let offset = memchr::memchr(byte, self.remaining_slice())?;
+		self.pos += offset;
+		Some(self.pos)
+	}
+
+	/// Searches forwards from the cursor for the first byte equal to any of `bytes`, and moves the
+	/// cursor to it. The byte pointed at by the cursor is included in the search.
+	///
+	/// Searches for up to three bytes are accelerated. Larger sets of bytes fall back to checking
+	/// each byte in turn.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the matching byte, or `None` if no
+	/// byte matches. In the latter case, the cursor will not be moved.
+	pub fn seek_to_any_of<const N: usize>(&mut self, bytes: &[u8; N]) -> Option<usize> {
+		self.record_seek();
+		let haystack = self.remaining_slice();
+		let offset = match bytes.as_slice() {
+			[] => None,
+			&[a] => memchr::memchr(a, haystack),
+			&[a, b] => memchr::memchr2(a, b, haystack),
+			&[a, b, c] => memchr::memchr3(a, b, c, haystack),
+			_ => haystack.iter().position(|byte| bytes.contains(byte)),
+		}?;
+		self.pos += offset;
+		Some(self.pos)
+	}
+
+	/// Searches forwards from the cursor for the first occurrence of `needle`, and moves the
+	/// cursor to its first byte. An occurrence starting at the cursor is included in the search.
+	///
+	/// If `needle` is empty, it is considered to match at the cursor.
+	///
+	/// Returns `Some(new_pos)`, where `new_pos` is the index of the first byte of the match, or
+	/// `None` if `needle` could not be found. In the latter case, the cursor will not be moved.
+	pub fn seek_to_subslice(&mut self, needle: &[u8]) -> Option<usize> {
+		self.record_seek();
+		let offset = memchr::memmem::find(self.remaining_slice(), needle)?;
+		self.pos += offset;
+		Some(self.pos)
+	}
+}
+
+#[cfg(all(test, feature = "core"))]
+mod memchr_tests {
+	use crate::CollectionCursor;
+
+	#[test]
+	fn seek_to_byte() {
+		let mut cursor = CollectionCursor::new(*b"key=value;next=1;");
+
+		assert_eq!(cursor.seek_to_byte(b'='), Some(3));
+		assert_eq!(
+			cursor.seek_to_byte(b'='),
+			Some(3),
+			"should match the byte at the cursor"
+		);
+
+		cursor.seek_forward_one();
+		assert_eq!(cursor.seek_to_byte(b'='), Some(14));
+		assert_eq!(
+			cursor.seek_to_byte(b'k'),
+			None,
+			"shouldn't search before the cursor"
+		);
+		assert_eq!(cursor.position(), 14, "shouldn't move when not found");
+
+		cursor.seek_to_end();
+		assert_eq!(cursor.seek_to_byte(b';'), None);
+	}
+
+	#[test]
+	fn seek_to_any_of() {
+		let mut cursor = CollectionCursor::new(*b"a,b;c d\te");
+
+		assert_eq!(cursor.seek_to_any_of(b";,"), Some(1));
+		cursor.seek_forward_one();
+		assert_eq!(cursor.seek_to_any_of(b";, "), Some(3));
+		cursor.seek_forward_one();
+		assert_eq!(
+			cursor.seek_to_any_of(b"xy\t "),
+			Some(5),
+			"should handle more than three bytes"
+		);
+		assert_eq!(cursor.seek_to_any_of(b"d"), Some(6));
+		assert_eq!(
+			cursor.seek_to_any_of(&[]),
+			None,
+			"should never match no bytes"
+		);
+		assert_eq!(cursor.seek_to_any_of(b"ab"), None);
+		assert_eq!(cursor.position(), 6, "shouldn't move when not found");
+	}
+
+	#[test]
+	fn seek_to_subslice() {
+		let mut cursor = CollectionCursor::new(*b"line one\r\nline two\r\n");
+
+		assert_eq!(cursor.seek_to_subslice(b"\r\n"), Some(8));
+		assert_eq!(
+			cursor.seek_to_subslice(b"\r\n"),
+			Some(8),
+			"should match at the cursor"
+		);
+		cursor.seek_relative(2).unwrap();
+		assert_eq!(cursor.seek_to_subslice(b"\r\n"), Some(18));
+		assert_eq!(
+			cursor.seek_to_subslice(b"one"),
+			None,
+			"shouldn't search before the cursor"
+		);
+		assert_eq!(cursor.position(), 18, "shouldn't move when not found");
+		assert_eq!(
+			cursor.seek_to_subslice(b""),
+			Some(18),
+			"an empty needle should match at the cursor"
+		);
+	}
+}
@@ -0,0 +1,199 @@
+use crate::CollectionCursor;
+
+/// Counts of the operations performed through a cursor, as returned by
+/// [`CollectionCursor::metrics()`].
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct CursorMetrics {
+	/// The number of seeks performed.
+	pub(crate) seeks: u64,
+	/// The number of items read.
+	pub(crate) reads: u64,
+	/// The number of items written.
+	pub(crate) writes: u64,
+	/// The number of items inserted.
+	pub(crate) inserts: u64,
+	/// The number of items removed.
+	pub(crate) removals: u64,
+}
+
+#[cfg(feature = "metrics")]
+impl CursorMetrics {
+	/// Returns the number of seeks performed. This counts each call to [`CollectionCursor::seek()`]
+	/// and the methods which move the cursor by an offset or to a fixed position, such as
+	/// [`CollectionCursor::seek_forward_one()`] or [`CollectionCursor::seek_to_end()`], whether or
+	/// not the cursor moved. Searches which move the cursor to their result, such as
+	/// [`CollectionCursor::seek_to_next()`] or [`CollectionCursor::skip_forward_while()`], are also
+	/// counted, whether or not they found a match.
+	pub fn seeks(&self) -> u64 {
+		self.seeks
+	}
+
+	/// Returns the number of items read by methods which move the cursor past them, such as
+	/// [`CollectionCursor::next_item()`], [`CollectionCursor::read_items()`], or
+	/// [`CollectionCursor::consume_prefix()`]. Peeking at items is not counted.
+	pub fn reads(&self) -> u64 {
+		self.reads
+	}
+
+	/// Returns the number of items overwritten, such as by
+	/// [`CollectionCursor::set_item_at_cursor()`] or [`CollectionCursor::write_items()`].
+	pub fn writes(&self) -> u64 {
+		self.writes
+	}
+
+	/// Returns the number of items inserted through the cursor.
+	pub fn inserts(&self) -> u64 {
+		self.inserts
+	}
+
+	/// Returns the number of items removed through the cursor.
+	pub fn removals(&self) -> u64 {
+		self.removals
+	}
+}
+
+#[cfg(feature = "metrics")]
+impl<Tape> CollectionCursor<Tape> {
+	/// Returns counts of the operations performed through this cursor since it was created, or
+	/// since [`Self::reset_metrics()`] was last called.
+	pub fn metrics(&self) -> CursorMetrics {
+		*self.metrics
+	}
+
+	/// Resets every count returned by [`Self::metrics()`] to `0`.
+	pub fn reset_metrics(&mut self) {
+		*self.metrics = CursorMetrics::default();
+	}
+}
+
+/// Adds `count` to one of the cursor's metrics. Without the `metrics` feature, this does nothing.
+macro_rules! record {
+	($cursor:expr, $metric:ident, $count:expr) => {{
+		#[cfg(feature = "metrics")]
+		{
+			let metrics = &mut *$cursor.metrics;
+			metrics.$metric = metrics.$metric.saturating_add($count as u64);
+		}
+		#[cfg(not(feature = "metrics"))]
+		{
+			let _ = $count;
+		}
+	}};
+}
+
+impl<Tape> CollectionCursor<Tape> {
+	/// Records a seek.
+	pub(crate) fn record_seek(&mut self) {
+		record!(self, seeks, 1_u8);
+	}
+
+	/// Records that `count` items were read.
+	pub(crate) fn record_reads(&mut self, count: usize) {
+		record!(self, reads, count);
+	}
+
+	/// Records that `count` items were written.
+	pub(crate) fn record_writes(&mut self, count: usize) {
+		record!(self, writes, count);
+	}
+
+	/// Records that `count` items were inserted.
+	pub(crate) fn record_inserts(&mut self, count: usize) {
+		record!(self, inserts, count);
+	}
+
+	/// Records that `count` items were removed.
+	pub(crate) fn record_removals(&mut self, count: usize) {
+		record!(self, removals, count);
+	}
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod metrics_tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use super::*;
+	use crate::SeekFrom;
+
+	#[test]
+	fn metrics() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+
+		cursor.seek(SeekFrom::Start(1)).unwrap();
+		cursor.seek_relative(10).unwrap_err();
+		cursor.seek_forward_one();
+		cursor.next_item();
+		let mut buf = [0; 2];
+		cursor.read_items(&mut buf);
+		cursor.set_item_at_cursor(10);
+		cursor.write_items(&[11, 12]);
+		cursor.insert_item_at_cursor(13);
+		cursor.seek_to_start();
+		cursor.remove_item_at_cursor();
+
+		let metrics = cursor.metrics();
+		assert_eq!(metrics.seeks(), 4, "should count failed seeks");
+		assert_eq!(metrics.reads(), 3);
+		assert_eq!(metrics.writes(), 2, "should count each item written");
+		assert_eq!(metrics.inserts(), 1);
+		assert_eq!(metrics.removals(), 1);
+
+		// The collection is now `[1, 2, 3, 4, 11, 13]`, with the cursor at `0`.
+		cursor.reset_metrics();
+		assert!(cursor.consume_prefix(&[1, 2]));
+		assert!(!cursor.consume_prefix(&[1]));
+		cursor.expect_item(&3).unwrap();
+		cursor.expect_item(&3).unwrap_err();
+		cursor.seek_to_next(&13);
+		cursor.seek_to_prev(&2);
+		cursor.seek_to_match(|&item| item == 11);
+		cursor.skip_forward_while(|&item| item < 13);
+		cursor.skip_backward_while(|_| false);
+		cursor.read_until_span(&4);
+		assert_eq!(cursor.position(), 6);
+		assert_eq!(
+			cursor.metrics().seeks(),
+			5,
+			"should count searches and skips"
+		);
+		assert_eq!(
+			cursor.metrics().reads(),
+			4,
+			"should count items consumed by matching"
+		);
+
+		cursor.seek_to_start_of_run();
+		cursor.seek_to_end_of_run();
+		assert_eq!(
+			cursor.metrics().seeks(),
+			5,
+			"shouldn't count seeks to a run when not pointing at an item"
+		);
+		cursor.seek_relative(-1).unwrap();
+		cursor.seek_to_start_of_run();
+		assert_eq!(cursor.metrics().seeks(), 7);
+
+		// `é`, followed by the varint encoding of `150`.
+		let mut bytes = CollectionCursor::new(Vec::from([0xC3, 0xA9, 0x96, 0x01]));
+		assert_eq!(bytes.next_char(), Ok('é'));
+		assert_eq!(bytes.read_varint_u64(), Ok(150));
+		assert_eq!(bytes.metrics().reads(), 4, "should count each byte decoded");
+
+		cursor.reset_metrics();
+		cursor.clear();
+
+		let metrics = cursor.metrics();
+		assert_eq!(metrics.removals(), 6, "should count each item cleared");
+
+		cursor.reset_metrics();
+		assert_eq!(cursor.metrics(), CursorMetrics::default());
+		assert_eq!(
+			cursor,
+			CollectionCursor::new(Vec::new()),
+			"shouldn't affect comparisons"
+		);
+	}
+}
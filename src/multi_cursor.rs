@@ -0,0 +1,232 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+
+/// A collection with several cursors pointing into it at once.
+///
+/// Each cursor is identified by its index, in the order the cursors were added. When an item is
+/// inserted or removed through one cursor, every other cursor is adjusted so that it keeps
+/// pointing at the same item, in the same way as a [`CollectionCursor`]'s marks.
+///
+/// Like a [`CollectionCursor`], every cursor is constrained to
+/// `0 <= position <= self.get_ref().len()`.
+///
+/// [`CollectionCursor`]: crate::CollectionCursor
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiCursor<Tape> {
+	/// The underlying collection that the cursors point into.
+	inner: Tape,
+	/// The position of each cursor.
+	positions: Vec<usize>,
+}
+
+impl<Tape> MultiCursor<Tape> {
+	/// Creates a new `MultiCursor` wrapping the provided collection, with no cursors.
+	pub const fn new(inner: Tape) -> Self {
+		Self {
+			inner,
+			positions: Vec::new(),
+		}
+	}
+
+	/// Returns a reference to the underlying collection.
+	pub fn get_ref(&self) -> &Tape {
+		&self.inner
+	}
+
+	/// Consumes the `MultiCursor`, returning the underlying collection.
+	pub fn into_inner(self) -> Tape {
+		self.inner
+	}
+
+	/// Returns the number of cursors.
+	pub fn cursor_count(&self) -> usize {
+		self.positions.len()
+	}
+
+	/// Returns the position of every cursor, in the order the cursors were added.
+	pub fn positions(&self) -> &[usize] {
+		&self.positions
+	}
+
+	/// Returns the position of `cursor`, or `None` if no such cursor exists.
+	pub fn position(&self, cursor: usize) -> Option<usize> {
+		self.positions.get(cursor).copied()
+	}
+
+	/// Removes `cursor`. Every cursor added after it has its index reduced by one.
+	///
+	/// Returns the removed cursor's position, or `None` if no such cursor exists.
+	pub fn remove_cursor(&mut self, cursor: usize) -> Option<usize> {
+		(cursor < self.positions.len()).then(|| self.positions.remove(cursor))
+	}
+}
+
+impl<Tape: IndexableCollection> MultiCursor<Tape> {
+	/// Adds a new cursor at `pos`.
+	///
+	/// Returns `Some(cursor)`, where `cursor` is the index of the new cursor, or `None` if
+	/// `pos > self.get_ref().len()`. In the latter case, no cursor is added.
+	pub fn add_cursor(&mut self, pos: usize) -> Option<usize> {
+		if pos > self.inner.len() {
+			return None;
+		}
+
+		self.positions.push(pos);
+		Some(self.positions.len() - 1)
+	}
+
+	/// Moves `cursor` to `pos`.
+	///
+	/// Returns `true` if the cursor was moved. If no such cursor exists, or
+	/// `pos > self.get_ref().len()`, returns `false` without moving any cursor.
+	pub fn set_position(&mut self, cursor: usize, pos: usize) -> bool {
+		let collection_len = self.inner.len();
+		match self.positions.get_mut(cursor) {
+			Some(position) if pos <= collection_len => {
+				*position = pos;
+				true
+			}
+			_ => false,
+		}
+	}
+
+	/// Returns a reference to the item pointed at by `cursor`, or `None` if no such cursor exists
+	/// or it is not pointing at an item.
+	pub fn get(&self, cursor: usize) -> Option<&Tape::Item> {
+		self.inner.get_item(self.position(cursor)?)
+	}
+}
+
+impl<Tape: IndexableCollectionMut> MultiCursor<Tape> {
+	/// Returns a mutable reference to the item pointed at by `cursor`, or `None` if no such cursor
+	/// exists or it is not pointing at an item.
+	pub fn get_mut(&mut self, cursor: usize) -> Option<&mut Tape::Item> {
+		self.inner.get_item_mut(self.position(cursor)?)
+	}
+
+	/// Sets the item pointed at by `cursor` to `item`.
+	///
+	/// Returns `true` if the item was set. If no such cursor exists, or it is not pointing at an
+	/// item, returns `false` without modifying the collection.
+	pub fn set(&mut self, cursor: usize, item: Tape::Item) -> bool {
+		match self.get_mut(cursor) {
+			Some(slot) => {
+				*slot = item;
+				true
+			}
+			None => false,
+		}
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> MultiCursor<Tape> {
+	/// Inserts `item` at `cursor`, shifting the following items to the right by one index.
+	/// `cursor` is left pointing at the inserted item, while every other cursor at or after it is
+	/// moved forwards so that it keeps pointing at the same item.
+	///
+	/// Returns `true` if the item was inserted. If no such cursor exists, returns `false` without
+	/// modifying the collection.
+	pub fn insert(&mut self, cursor: usize, item: Tape::Item) -> bool {
+		let Some(pos) = self.position(cursor) else {
+			return false;
+		};
+
+		self.inner.insert_item(pos, item);
+		for (other, position) in self.positions.iter_mut().enumerate() {
+			if other != cursor && *position >= pos {
+				*position += 1;
+			}
+		}
+		true
+	}
+
+	/// Removes and returns the item pointed at by `cursor`. Every cursor after it is moved
+	/// backwards so that it keeps pointing at the same item, while every cursor which pointed at
+	/// the removed item is left pointing at the item which followed it.
+	///
+	/// Returns `None` without modifying the collection if no such cursor exists, or it is not
+	/// pointing at an item.
+	pub fn remove(&mut self, cursor: usize) -> Option<Tape::Item> {
+		let pos = self.position(cursor)?;
+		let item = self.inner.remove_item(pos)?;
+		for position in &mut self.positions {
+			if *position > pos {
+				*position -= 1;
+			}
+		}
+		Some(item)
+	}
+}
+
+#[cfg(test)]
+mod multi_cursor_tests {
+	use alloc::vec::Vec;
+
+	use super::*;
+
+	#[test]
+	fn cursors() {
+		let mut cursors = MultiCursor::new(Vec::from([0, 1, 2, 3]));
+
+		assert_eq!(cursors.add_cursor(1), Some(0));
+		assert_eq!(cursors.add_cursor(4), Some(1));
+		assert_eq!(
+			cursors.add_cursor(5),
+			None,
+			"shouldn't add a cursor out of bounds"
+		);
+		assert_eq!(cursors.positions(), [1, 4]);
+
+		assert_eq!(cursors.get(0), Some(&1));
+		assert_eq!(cursors.get(1), None);
+		assert!(cursors.set(0, 10));
+		assert!(!cursors.set(1, 10), "shouldn't set an item at the end");
+		assert_eq!(*cursors.get_ref(), [0, 10, 2, 3]);
+
+		assert!(cursors.set_position(1, 3));
+		assert!(!cursors.set_position(1, 5));
+		assert!(!cursors.set_position(2, 0));
+		assert_eq!(cursors.remove_cursor(0), Some(1));
+		assert_eq!(cursors.positions(), [3]);
+		assert_eq!(cursors.remove_cursor(1), None);
+	}
+
+	#[test]
+	fn edits_adjust_other_cursors() {
+		let mut cursors = MultiCursor::new(Vec::from([0, 1, 2, 3]));
+		cursors.add_cursor(1);
+		cursors.add_cursor(1);
+		cursors.add_cursor(3);
+		cursors.add_cursor(0);
+
+		assert!(cursors.insert(0, 10));
+		assert_eq!(*cursors.get_ref(), [0, 10, 1, 2, 3]);
+		assert_eq!(
+			cursors.positions(),
+			[1, 2, 4, 0],
+			"should shift the other cursors at or after the insertion"
+		);
+		assert_eq!(cursors.get(1), Some(&1));
+		assert_eq!(cursors.get(2), Some(&3));
+
+		assert_eq!(cursors.remove(1), Some(1));
+		assert_eq!(*cursors.get_ref(), [0, 10, 2, 3]);
+		assert_eq!(cursors.positions(), [1, 2, 3, 0]);
+		assert_eq!(
+			cursors.get(2),
+			Some(&3),
+			"should keep pointing at the same item"
+		);
+
+		cursors.set_position(1, 4);
+		assert_eq!(cursors.remove(1), None, "shouldn't remove at the end");
+		assert!(
+			!cursors.insert(4, 0),
+			"shouldn't insert at a missing cursor"
+		);
+	}
+}
@@ -0,0 +1,327 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{CollectionCursor, IndexableCollection, IndexableCollectionResizable};
+
+/// What happened to a cursor, as reported to a [`CursorObserver`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum CursorEventKind {
+	/// The cursor was moved, without the collection being structurally edited.
+	Seek,
+	/// `count` items were inserted at `index`.
+	Insert {
+		/// The index the first item was inserted at.
+		index: usize,
+		/// The number of items inserted.
+		count: usize,
+	},
+	/// `count` items were removed, starting from `index`.
+	Remove {
+		/// The index the first item was removed from.
+		index: usize,
+		/// The number of items removed.
+		count: usize,
+	},
+	/// The items within `start..end` were reordered, without any being inserted or removed.
+	Reorder {
+		/// The first index whose item may have changed.
+		start: usize,
+		/// The index just after the last index whose item may have changed.
+		end: usize,
+	},
+}
+
+impl CursorEventKind {
+	/// Returns whether this event leaves the collection as it was, such as an insertion of `0`
+	/// items.
+	fn changes_nothing(&self) -> bool {
+		match *self {
+			Self::Seek => false,
+			Self::Insert { count, .. } | Self::Remove { count, .. } => count == 0,
+			Self::Reorder { start, end } => end.saturating_sub(start) < 2,
+		}
+	}
+}
+
+/// A movement of, or edit made through, an [`ObservedCursor`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct CursorEvent {
+	/// What happened to the cursor.
+	pub(crate) kind: CursorEventKind,
+	/// The cursor's position before the event.
+	pub(crate) old_pos: usize,
+	/// The cursor's position after the event.
+	pub(crate) new_pos: usize,
+}
+
+impl CursorEvent {
+	/// Returns what happened to the cursor.
+	pub fn kind(&self) -> CursorEventKind {
+		self.kind
+	}
+
+	/// Returns the cursor's position before the method which caused the event was called.
+	pub fn old_pos(&self) -> usize {
+		self.old_pos
+	}
+
+	/// Returns the cursor's position after the method which caused the event returned.
+	pub fn new_pos(&self) -> usize {
+		self.new_pos
+	}
+}
+
+/// Receives a [`CursorEvent`] for every movement of, and edit made through, an [`ObservedCursor`].
+///
+/// This is implemented for every `FnMut(CursorEvent)`, so a closure may be used as an observer.
+pub trait CursorObserver {
+	/// Called after the cursor is moved, or its collection is edited.
+	fn notify(&mut self, event: CursorEvent);
+}
+
+impl<F: FnMut(CursorEvent)> CursorObserver for F {
+	fn notify(&mut self, event: CursorEvent) {
+		self(event);
+	}
+}
+
+/// Wraps a [`CollectionCursor`], notifying an observer whenever the cursor is moved or its
+/// collection is structurally edited.
+///
+/// The wrapped cursor can be read through [`Deref`], but can only be moved or edited through
+/// [`Self::with_cursor()`] or the methods on this type, so that no event is missed. Moving the
+/// cursor without editing the collection only notifies the observer if the cursor's position
+/// changed.
+///
+/// Every insertion, removal and reordering made through the cursor's own methods is reported.
+/// Items overwritten in place are not, and neither are edits made through
+/// [`CollectionCursor::get_mut()`] or similar, as the cursor cannot see them.
+#[derive(Clone, Debug)]
+pub struct ObservedCursor<Tape, O: CursorObserver> {
+	/// The cursor being observed.
+	cursor: CollectionCursor<Tape>,
+	/// The observer to notify of events.
+	observer: O,
+}
+
+impl<Tape, O: CursorObserver> ObservedCursor<Tape, O> {
+	/// Wraps `cursor`, notifying `observer` of every later movement and edit.
+	pub fn new(cursor: CollectionCursor<Tape>, observer: O) -> Self {
+		Self { cursor, observer }
+	}
+
+	/// Returns a reference to the observer.
+	pub fn observer(&self) -> &O {
+		&self.observer
+	}
+
+	/// Returns a mutable reference to the observer.
+	pub fn observer_mut(&mut self) -> &mut O {
+		&mut self.observer
+	}
+
+	/// Unwraps this `ObservedCursor`, returning the cursor and the observer.
+	pub fn into_parts(mut self) -> (CollectionCursor<Tape>, O) {
+		*self.cursor.observed_edits = None;
+		(self.cursor, self.observer)
+	}
+
+	/// Runs `f` with the wrapped cursor, then notifies the observer of what `f` did. Returns the
+	/// value returned by `f`.
+	///
+	/// The observer receives one event for each structural edit `f` made through the cursor, in
+	/// the order they were made. If `f` made no structural edits, but moved the cursor, the
+	/// observer instead receives a single [`CursorEventKind::Seek`] event. Either way, each event
+	/// carries the cursor's positions from before and after `f` was run.
+	pub fn with_cursor<R, F: FnOnce(&mut CollectionCursor<Tape>) -> R>(&mut self, f: F) -> R {
+		let old_pos = self.cursor.pos;
+		*self.cursor.observed_edits = Some(Vec::new());
+		let result = f(&mut self.cursor);
+		let edits = self.cursor.observed_edits.take().unwrap_or_default();
+
+		let new_pos = self.cursor.pos;
+		let mut notify = |kind| {
+			self.observer.notify(CursorEvent {
+				kind,
+				old_pos,
+				new_pos,
+			});
+		};
+		if !edits.is_empty() {
+			edits.into_iter().for_each(&mut notify);
+		} else if new_pos != old_pos {
+			notify(CursorEventKind::Seek);
+		}
+		result
+	}
+}
+
+impl<Tape, O: CursorObserver> Deref for ObservedCursor<Tape, O> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection, O: CursorObserver> ObservedCursor<Tape, O> {
+	forward_cursor_movement!(with_cursor);
+}
+
+impl<Tape: IndexableCollectionResizable, O: CursorObserver> ObservedCursor<Tape, O> {
+	/// Inserts `item` at the cursor, as described by
+	/// [`CollectionCursor::insert_item_at_cursor()`], then notifies the observer.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. See [`CollectionCursor::insert_item_at_cursor()`].
+	pub fn insert_item_at_cursor(&mut self, item: Tape::Item) {
+		self.with_cursor(|cursor| cursor.insert_item_at_cursor(item));
+	}
+
+	/// Removes and returns the item at the cursor, as described by
+	/// [`CollectionCursor::remove_item_at_cursor()`]. The observer is only notified if an item was
+	/// removed.
+	pub fn remove_item_at_cursor(&mut self) -> Option<Tape::Item> {
+		self.with_cursor(CollectionCursor::remove_item_at_cursor)
+	}
+
+	/// Removes every item from the collection and moves the cursor to index `0`, then notifies the
+	/// observer with a single [`CursorEventKind::Remove`] event. The observer is not notified if
+	/// the collection was already empty and the cursor did not move.
+	pub fn clear(&mut self) {
+		self.with_cursor(CollectionCursor::clear);
+	}
+}
+
+impl<Tape> CollectionCursor<Tape> {
+	/// Records a structural edit, so that it can be reported to the observer of the
+	/// [`ObservedCursor`] wrapping this cursor. If no `ObservedCursor` is running a method on this
+	/// cursor, or the edit changed nothing, this does nothing.
+	pub(crate) fn notify_edit(&mut self, kind: CursorEventKind) {
+		if kind.changes_nothing() {
+			return;
+		}
+		if let Some(edits) = &mut *self.observed_edits {
+			edits.push(kind);
+		}
+	}
+}
+
+#[cfg(test)]
+mod observer_tests {
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use super::*;
+	use crate::CursorSpan;
+
+	fn event(kind: CursorEventKind, old_pos: usize, new_pos: usize) -> CursorEvent {
+		CursorEvent {
+			kind,
+			old_pos,
+			new_pos,
+		}
+	}
+
+	#[test]
+	fn observed_cursor() {
+		let mut events = Vec::new();
+		let observer = |event| events.push(event);
+		let mut cursor = ObservedCursor::new(CollectionCursor::new(Vec::from([0, 1, 2])), observer);
+
+		cursor.seek_relative(2).unwrap();
+		cursor.seek_relative(5).unwrap_err();
+		cursor.seek_to_end();
+		cursor.seek_to_end();
+		cursor.insert_item_at_cursor(3);
+		assert_eq!(cursor.remove_item_at_cursor(), Some(3));
+		assert_eq!(cursor.remove_item_at_cursor(), None);
+		assert_eq!(
+			cursor.get_ref(),
+			&[0, 1, 2],
+			"should allow reading the cursor"
+		);
+		cursor.clear();
+		cursor.clear();
+		drop(cursor);
+
+		assert_eq!(
+			events,
+			[
+				event(CursorEventKind::Seek, 0, 2),
+				event(CursorEventKind::Seek, 2, 3),
+				event(CursorEventKind::Insert { index: 3, count: 1 }, 3, 3),
+				event(CursorEventKind::Remove { index: 3, count: 1 }, 3, 3),
+				event(CursorEventKind::Remove { index: 0, count: 3 }, 3, 0),
+			],
+			"should only report seeks which moved the cursor, and successful edits"
+		);
+	}
+
+	#[test]
+	fn with_cursor() {
+		let mut events = Vec::new();
+		let observer = |event| events.push(event);
+		let collection = Vec::from([0, 1, 2, 3, 4, 5]);
+		let mut cursor = ObservedCursor::new(CollectionCursor::new(collection), observer);
+
+		assert_eq!(
+			cursor.with_cursor(|cursor| cursor.next_item().copied()),
+			Some(0)
+		);
+		cursor.with_cursor(|cursor| cursor.seek_to_next(&4));
+		cursor.with_cursor(|cursor| cursor.insert_slice_at_cursor(&[10, 11]));
+		cursor.with_cursor(|cursor| cursor.transpose_at_cursor());
+		cursor.with_cursor(|cursor| cursor.move_item(0, 3));
+		cursor.with_cursor(|cursor| cursor.move_item(2, 2));
+		cursor.with_cursor(|cursor| cursor.move_range_to_cursor(CursorSpan::new(1, 3)));
+		cursor.with_cursor(CollectionCursor::rotate_cursor_to_front);
+		assert_eq!(*cursor.get_ref(), [2, 10, 11, 4, 5, 1, 0, 3]);
+		drop(cursor);
+
+		assert_eq!(
+			events,
+			[
+				event(CursorEventKind::Seek, 0, 1),
+				event(CursorEventKind::Seek, 1, 4),
+				event(CursorEventKind::Insert { index: 4, count: 2 }, 4, 4),
+				event(CursorEventKind::Reorder { start: 3, end: 5 }, 4, 5),
+				event(CursorEventKind::Reorder { start: 0, end: 4 }, 5, 5),
+				event(CursorEventKind::Reorder { start: 1, end: 5 }, 5, 3),
+				event(CursorEventKind::Reorder { start: 0, end: 8 }, 3, 0),
+			],
+			"should report every structural edit"
+		);
+	}
+
+	#[test]
+	fn several_edits_in_one_call() {
+		let mut events = Vec::new();
+		let observer = |event| events.push(event);
+		let collection = VecDeque::from([0, 1, 2]);
+		let mut cursor = ObservedCursor::new(CollectionCursor::new(collection), observer);
+
+		cursor.with_cursor(|cursor| {
+			cursor.push_front(3);
+			cursor.seek_to_end();
+			cursor.pop_back();
+		});
+		cursor.with_cursor(|cursor| {
+			cursor.pop_front();
+			cursor.pop_front();
+		});
+		drop(cursor);
+
+		assert_eq!(
+			events,
+			[
+				event(CursorEventKind::Insert { index: 0, count: 1 }, 0, 3),
+				event(CursorEventKind::Remove { index: 3, count: 1 }, 0, 3),
+				event(CursorEventKind::Remove { index: 0, count: 1 }, 3, 1),
+				event(CursorEventKind::Remove { index: 0, count: 1 }, 3, 1),
+			],
+			"should report each edit in the order it was made"
+		);
+	}
+}
@@ -0,0 +1,559 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+use crate::{
+	CollectionCursor, IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable,
+	PatchError,
+};
+
+/// A single edit within a [`Patch`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PatchOp<Item> {
+	/// Sets the slot at `index` to `item`.
+	Set {
+		/// The index of the slot to set.
+		index: usize,
+		/// The item to place into the slot.
+		item: Item,
+	},
+	/// Inserts `item` at `index`, shifting the following items to the right.
+	Insert {
+		/// The index to insert the item at.
+		index: usize,
+		/// The item to insert.
+		item: Item,
+	},
+	/// Removes the item at `index`, shifting the following items to the left.
+	Remove {
+		/// The index of the item to remove.
+		index: usize,
+	},
+	/// Removes every item from the collection.
+	Clear,
+}
+
+/// A list of edits, which can be applied to a cursor with [`CollectionCursor::apply_patch()`].
+///
+/// Each edit's index refers to the collection as it is after every earlier edit has been applied.
+/// Patches are usually recorded by a [`RecordingCursor`], but may also be built by hand.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Patch<Item> {
+	/// The edits within the patch, in the order they should be applied.
+	ops: Vec<PatchOp<Item>>,
+}
+
+impl<Item> Patch<Item> {
+	/// Creates a new, empty patch.
+	pub const fn new() -> Self {
+		Self { ops: Vec::new() }
+	}
+
+	/// Returns the edits within the patch, in the order they should be applied.
+	pub fn ops(&self) -> &[PatchOp<Item>] {
+		&self.ops
+	}
+
+	/// Returns the number of edits within the patch.
+	pub fn len(&self) -> usize {
+		self.ops.len()
+	}
+
+	/// Returns whether the patch contains no edits.
+	pub fn is_empty(&self) -> bool {
+		self.ops.is_empty()
+	}
+
+	/// Appends `op` to the end of the patch.
+	pub fn push(&mut self, op: PatchOp<Item>) {
+		self.ops.push(op);
+	}
+
+	/// Consumes the patch, returning the edits within it.
+	pub fn into_ops(self) -> Vec<PatchOp<Item>> {
+		self.ops
+	}
+
+	/// Checks that every edit within the patch targets an index within the bounds of a collection
+	/// which starts out with `collection_len` items.
+	fn validate(&self, mut collection_len: usize) -> Result<(), PatchError> {
+		for (op, patch_op) in self.ops.iter().enumerate() {
+			let (index, in_bounds) = match *patch_op {
+				PatchOp::Set { index, .. } => (index, index < collection_len),
+				PatchOp::Insert { index, .. } => (index, index <= collection_len),
+				PatchOp::Remove { index } => (index, index < collection_len),
+				PatchOp::Clear => (0, true),
+			};
+			if !in_bounds {
+				return Err(PatchError {
+					op,
+					index,
+					collection_len,
+				});
+			}
+
+			collection_len = match patch_op {
+				PatchOp::Set { .. } => collection_len,
+				PatchOp::Insert { .. } => collection_len + 1,
+				PatchOp::Remove { .. } => collection_len - 1,
+				PatchOp::Clear => 0,
+			};
+		}
+		Ok(())
+	}
+}
+
+impl<Item> Default for Patch<Item> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<Item> From<Vec<PatchOp<Item>>> for Patch<Item> {
+	fn from(ops: Vec<PatchOp<Item>>) -> Self {
+		Self { ops }
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Applies every edit within `patch` to the inner collection, in order. `patch` is left as-is,
+	/// so it may be applied to other cursors too.
+	///
+	/// The cursor, checkpoints, and marks are adjusted so that they keep pointing at the same
+	/// items. If the item the cursor pointed at is removed, the cursor is moved to the item which
+	/// followed it. If the collection is cleared, the cursor is moved to index `0`.
+	///
+	/// # Errors
+	/// Returns an error if an edit within `patch` targets an index outside of the collection's
+	/// bounds. The whole patch is checked before any edit is applied, so the collection is left
+	/// untouched in this case.
+	///
+	/// # Panics
+	/// Panics if an insert operation panics.
+	pub fn apply_patch(&mut self, patch: &Patch<Tape::Item>) -> Result<(), PatchError> {
+		patch.validate(self.inner.len())?;
+
+		for op in &patch.ops {
+			self.apply_patch_op(op.clone(), None);
+		}
+		Ok(())
+	}
+}
+
+impl<Tape: IndexableCollectionMut> CollectionCursor<Tape> {
+	/// Sets the slot at `index` to `item`, as a [`PatchOp::Set`] does, without moving the cursor.
+	///
+	/// Returns the item which was in the slot, or `None` if `index` is out of bounds. In the latter
+	/// case, the collection is left untouched.
+	pub(crate) fn apply_set_op(&mut self, index: usize, item: Tape::Item) -> Option<Tape::Item> {
+		let slot = self.inner.get_item_mut(index)?;
+		let old_item = core::mem::replace(slot, item);
+		self.record_writes(1);
+		Some(old_item)
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
+	/// Applies a single edit of a patch, as described by [`Self::apply_patch()`]. An edit which
+	/// targets an index out of bounds is skipped, except for an insertion, which panics if the
+	/// inner collection does.
+	///
+	/// If `inverse` is given, the edits which undo `op` are pushed onto it, in the reverse of the
+	/// order they must be applied in.
+	pub(crate) fn apply_patch_op(
+		&mut self,
+		op: PatchOp<Tape::Item>,
+		inverse: Option<&mut Vec<PatchOp<Tape::Item>>>,
+	) {
+		let inverse_op = match op {
+			PatchOp::Set { index, item } => {
+				let Some(old_item) = self.apply_set_op(index, item) else {
+					return;
+				};
+				PatchOp::Set {
+					index,
+					item: old_item,
+				}
+			}
+			PatchOp::Insert { index, item } => {
+				self.inner.insert_item(index, item);
+				if self.pos >= index {
+					self.pos += 1;
+				}
+				self.shift_saved_positions_for_insert(index, 1);
+				PatchOp::Remove { index }
+			}
+			PatchOp::Remove { index } => {
+				let Some(item) = self.inner.remove_item(index) else {
+					return;
+				};
+				if self.pos > index {
+					self.pos -= 1;
+				}
+				self.shift_saved_positions_for_remove(index, 1);
+				PatchOp::Insert { index, item }
+			}
+			PatchOp::Clear => {
+				let Some(inverse) = inverse else {
+					self.clear();
+					return;
+				};
+				let collection_len = self.inner.len();
+				for index in (0..collection_len).rev() {
+					if let Some(item) = self.inner.remove_item(index) {
+						inverse.push(PatchOp::Insert { index, item });
+					}
+				}
+				self.pos = 0;
+				self.shift_saved_positions_for_remove(0, collection_len);
+				return;
+			}
+		};
+		if let Some(inverse) = inverse {
+			inverse.push(inverse_op);
+		}
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: PartialEq + Clone,
+{
+	/// Computes a patch which, when applied with [`Self::apply_patch()`] to a cursor over a
+	/// collection equal to this cursor's, turns that collection into one equal to `other`. Neither
+	/// collection, nor the cursor, is modified.
+	///
+	/// Items at the beginning and end which both collections share are left untouched. Between
+	/// them, the patch keeps the longest common subsequence of items where that is cheap to find,
+	/// and otherwise overwrites items in place.
+	///
+	/// # Performance
+	/// Finding the longest common subsequence takes `O(n * m)` time and memory, where `n` and `m`
+	/// are the number of items which differ between the collections. It is skipped if `n * m` is
+	/// larger than 65536, in which case the patch may contain more edits than necessary.
+	pub fn diff<Other>(&self, other: &Other) -> Patch<Tape::Item>
+	where
+		Other: IndexableCollection<Item = Tape::Item>,
+	{
+		/// The largest number of cells in the longest common subsequence table we'll allocate.
+		const MAX_TABLE_SIZE: usize = 1 << 16;
+
+		let (old_len, new_len) = (self.inner.len(), other.len());
+		let mut patch = Patch::new();
+		if new_len == 0 {
+			if old_len != 0 {
+				patch.push(PatchOp::Clear);
+			}
+			return patch;
+		}
+
+		let old = |index| self.inner.get_item(index);
+		let new = |index| other.get_item(index);
+		let prefix_len = (0..old_len.min(new_len))
+			.take_while(|&index| old(index) == new(index))
+			.count();
+		let suffix_len = (0..old_len.min(new_len) - prefix_len)
+			.take_while(|&offset| old(old_len - 1 - offset) == new(new_len - 1 - offset))
+			.count();
+		let old_middle = prefix_len..old_len - suffix_len;
+		let new_middle = prefix_len..new_len - suffix_len;
+		let (rows, columns) = (old_middle.len(), new_middle.len());
+
+		// `lcs[i * (columns + 1) + j]` is the length of the longest common subsequence between the
+		// old items from `i` onwards and the new items from `j` onwards, relative to the middle.
+		let lcs = if rows.saturating_mul(columns) <= MAX_TABLE_SIZE {
+			let mut lcs = alloc::vec![0; (rows + 1) * (columns + 1)];
+			for i in (0..rows).rev() {
+				for j in (0..columns).rev() {
+					let cell = i * (columns + 1) + j;
+					lcs[cell] = if old(prefix_len + i) == new(prefix_len + j) {
+						lcs[cell + columns + 2] + 1
+					} else {
+						lcs[cell + columns + 1].max(lcs[cell + 1])
+					};
+				}
+			}
+			Some(lcs)
+		} else {
+			None
+		};
+
+		let (mut i, mut j) = (0, 0);
+		let mut index = prefix_len;
+		while i < rows || j < columns {
+			let old_item = (i < rows).then(|| old(prefix_len + i)).flatten();
+			let new_item = (j < columns).then(|| new(prefix_len + j)).flatten();
+			let remove = match (&lcs, old_item, new_item) {
+				(_, Some(old_item), Some(new_item)) if old_item == new_item => {
+					i += 1;
+					j += 1;
+					index += 1;
+					continue;
+				}
+				(_, None, Some(_)) => false,
+				(_, Some(_), None) => true,
+				(Some(lcs), Some(_), Some(_)) => {
+					let cell = i * (columns + 1) + j;
+					lcs[cell + columns + 1] >= lcs[cell + 1]
+				}
+				(None, Some(_), Some(new_item)) => {
+					patch.push(PatchOp::Set {
+						index,
+						item: new_item.clone(),
+					});
+					i += 1;
+					j += 1;
+					index += 1;
+					continue;
+				}
+				(_, None, None) => break,
+			};
+
+			if remove {
+				patch.push(PatchOp::Remove { index });
+				i += 1;
+			} else if let Some(new_item) = new_item {
+				// A removal directly followed by an insertion at the same index is an overwrite.
+				let item = new_item.clone();
+				match patch.ops.last() {
+					Some(PatchOp::Remove { index: removed }) if *removed == index => {
+						patch.ops.pop();
+						patch.push(PatchOp::Set { index, item });
+					}
+					_ => patch.push(PatchOp::Insert { index, item }),
+				}
+				j += 1;
+				index += 1;
+			}
+		}
+		patch
+	}
+}
+
+/// Wraps a [`CollectionCursor`], recording every edit made through it into a [`Patch`], so that
+/// the edits can be replayed onto another cursor with [`CollectionCursor::apply_patch()`].
+///
+/// The wrapped cursor can be read through [`Deref`], but can only be moved or edited through the
+/// methods on this type, so that no edit is missed. Moving the cursor is not recorded.
+#[derive(Clone, Debug)]
+pub struct RecordingCursor<Tape: IndexableCollection> {
+	/// The cursor whose edits are being recorded.
+	cursor: CollectionCursor<Tape>,
+	/// The edits recorded so far.
+	patch: Patch<Tape::Item>,
+	/// Whether edits are currently being recorded.
+	recording: bool,
+}
+
+impl<Tape: IndexableCollection> RecordingCursor<Tape> {
+	/// Wraps `cursor`, recording every later edit.
+	pub fn new(cursor: CollectionCursor<Tape>) -> Self {
+		Self {
+			cursor,
+			patch: Patch::new(),
+			recording: true,
+		}
+	}
+
+	/// Returns whether edits are currently being recorded.
+	pub fn is_recording(&self) -> bool {
+		self.recording
+	}
+
+	/// Sets whether edits should be recorded. While recording is paused, edits are still made, but
+	/// are left out of the patch.
+	pub fn set_recording(&mut self, recording: bool) {
+		self.recording = recording;
+	}
+
+	/// Returns the edits recorded so far.
+	pub fn patch(&self) -> &Patch<Tape::Item> {
+		&self.patch
+	}
+
+	/// Returns the edits recorded so far, and starts recording into a new, empty patch.
+	pub fn take_patch(&mut self) -> Patch<Tape::Item> {
+		core::mem::take(&mut self.patch)
+	}
+
+	/// Unwraps this `RecordingCursor`, returning the cursor and the edits recorded so far.
+	pub fn into_parts(self) -> (CollectionCursor<Tape>, Patch<Tape::Item>) {
+		(self.cursor, self.patch)
+	}
+
+	/// Appends `op` to the patch, if edits are being recorded.
+	fn record(&mut self, op: PatchOp<Tape::Item>) {
+		if self.recording {
+			self.patch.push(op);
+		}
+	}
+
+	/// Runs `f` with the wrapped cursor. Used to move the cursor, which is not recorded.
+	fn move_cursor<R, F: FnOnce(&mut CollectionCursor<Tape>) -> R>(&mut self, f: F) -> R {
+		f(&mut self.cursor)
+	}
+}
+
+impl<Tape: IndexableCollection> Deref for RecordingCursor<Tape> {
+	type Target = CollectionCursor<Tape>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.cursor
+	}
+}
+
+impl<Tape: IndexableCollection> RecordingCursor<Tape> {
+	forward_cursor_movement!(move_cursor);
+}
+
+impl<Tape: IndexableCollectionMut> RecordingCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Sets the slot at the cursor to `item`, as described by
+	/// [`CollectionCursor::set_item_at_cursor()`], recording the edit.
+	///
+	/// # Panics
+	/// Panics if the set operation panics. See [`CollectionCursor::set_item_at_cursor()`].
+	pub fn set_item_at_cursor(&mut self, item: Tape::Item) {
+		let index = self.cursor.pos;
+		self.cursor.set_item_at_cursor(item.clone());
+		self.record(PatchOp::Set { index, item });
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> RecordingCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Inserts `item` at the cursor, as described by
+	/// [`CollectionCursor::insert_item_at_cursor()`], recording the edit.
+	///
+	/// # Panics
+	/// Panics if the insert operation panics. See [`CollectionCursor::insert_item_at_cursor()`].
+	pub fn insert_item_at_cursor(&mut self, item: Tape::Item) {
+		let index = self.cursor.pos;
+		self.cursor.insert_item_at_cursor(item.clone());
+		self.record(PatchOp::Insert { index, item });
+	}
+
+	/// Removes and returns the item at the cursor, as described by
+	/// [`CollectionCursor::remove_item_at_cursor()`]. The edit is only recorded if an item was
+	/// removed.
+	pub fn remove_item_at_cursor(&mut self) -> Option<Tape::Item> {
+		let index = self.cursor.pos;
+		let item = self.cursor.remove_item_at_cursor()?;
+		self.record(PatchOp::Remove { index });
+		Some(item)
+	}
+
+	/// Removes every item from the collection and moves the cursor to index `0`, recording the
+	/// edit.
+	pub fn clear(&mut self) {
+		self.cursor.clear();
+		self.record(PatchOp::Clear);
+	}
+}
+
+#[cfg(test)]
+mod patch_tests {
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use super::*;
+
+	#[test]
+	fn record_and_replay() {
+		let mut recorder = RecordingCursor::new(CollectionCursor::new(Vec::from([0, 1, 2, 3])));
+		let mut replica = CollectionCursor::new(VecDeque::from([0, 1, 2, 3]));
+		replica.seek_relative(3).unwrap();
+
+		recorder.seek_relative(1).unwrap();
+		recorder.set_item_at_cursor(10);
+		recorder.insert_item_at_cursor(11);
+		recorder.seek_to_end();
+		assert_eq!(recorder.remove_item_at_cursor(), None);
+		recorder.seek_relative(-2).unwrap();
+		assert_eq!(recorder.remove_item_at_cursor(), Some(2));
+		recorder.set_recording(false);
+		recorder.seek_to_start();
+		recorder.set_item_at_cursor(20);
+		recorder.set_recording(true);
+
+		let patch = recorder.take_patch();
+		assert_eq!(
+			patch.ops(),
+			[
+				PatchOp::Set { index: 1, item: 10 },
+				PatchOp::Insert { index: 1, item: 11 },
+				PatchOp::Remove { index: 3 },
+			],
+			"should only record edits made while recording"
+		);
+		assert!(recorder.patch().is_empty());
+
+		replica.apply_patch(&patch).unwrap();
+		assert_eq!(*replica.get_ref(), [0, 11, 10, 3]);
+		assert_eq!(
+			replica.position(),
+			3,
+			"should keep pointing at the same item"
+		);
+
+		assert_eq!(
+			replica.apply_patch(&Patch::from(Vec::from([
+				PatchOp::Clear,
+				PatchOp::Insert { index: 0, item: 5 },
+				PatchOp::Remove { index: 1 },
+			]))),
+			Err(PatchError {
+				op: 2,
+				index: 1,
+				collection_len: 1,
+			})
+		);
+		assert_eq!(
+			*replica.get_ref(),
+			[0, 11, 10, 3],
+			"shouldn't apply any edit from an invalid patch"
+		);
+	}
+
+	#[test]
+	fn diff() {
+		let diff_and_apply = |old: &[i32], new: &[i32]| {
+			let mut cursor = CollectionCursor::new(Vec::from(old));
+			let patch = cursor.diff(&Vec::from(new));
+			cursor.apply_patch(&patch).unwrap();
+			assert_eq!(*cursor.get_ref(), new, "should turn {old:?} into {new:?}");
+			patch.into_ops()
+		};
+
+		assert_eq!(diff_and_apply(&[0, 1, 2], &[0, 1, 2]), []);
+		assert_eq!(diff_and_apply(&[0, 1, 2], &[]), [PatchOp::Clear]);
+		assert_eq!(
+			diff_and_apply(&[0, 1, 2, 3], &[0, 9, 1, 3]),
+			[
+				PatchOp::Insert { index: 1, item: 9 },
+				PatchOp::Remove { index: 3 },
+			],
+			"should keep the longest common subsequence"
+		);
+		assert_eq!(
+			diff_and_apply(&[0, 1, 2], &[0, 5, 2]),
+			[PatchOp::Set { index: 1, item: 5 }],
+			"should overwrite replaced items"
+		);
+		diff_and_apply(&[], &[1, 2]);
+		diff_and_apply(&[4, 3, 2, 1], &[1, 2, 3, 4, 5]);
+
+		let old = Vec::from_iter(0..300);
+		let new = Vec::from_iter((0..300).rev());
+		diff_and_apply(&old, &new);
+	}
+}
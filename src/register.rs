@@ -0,0 +1,237 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{CollectionCursor, CursorSpan, IndexableCollection, IndexableCollectionResizable};
+
+/// A buffer holding items which were copied or cut from a cursor, so that they can be pasted into
+/// the same cursor, or a different one.
+///
+/// Items are placed into a register by [`CollectionCursor::copy_to_register()`] or
+/// [`CollectionCursor::cut_to_register()`], and taken back out by
+/// [`CollectionCursor::paste_from_register()`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Register<Item> {
+	/// The items held by the register, in the order they were copied.
+	items: Vec<Item>,
+}
+
+impl<Item> Register<Item> {
+	/// Creates a new, empty register.
+	pub const fn new() -> Self {
+		Self { items: Vec::new() }
+	}
+
+	/// Returns the items held by the register.
+	pub fn items(&self) -> &[Item] {
+		&self.items
+	}
+
+	/// Returns the number of items held by the register.
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	/// Returns whether the register holds no items.
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+
+	/// Removes every item from the register.
+	pub fn clear(&mut self) {
+		self.items.clear();
+	}
+
+	/// Consumes the register, returning the items it held.
+	pub fn into_items(self) -> Vec<Item> {
+		self.items
+	}
+}
+
+impl<Item> Default for Register<Item> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<Item> From<Vec<Item>> for Register<Item> {
+	fn from(items: Vec<Item>) -> Self {
+		Self { items }
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Replaces the contents of `register` with clones of the items within `span`. The cursor and
+	/// the inner collection are left untouched.
+	///
+	/// Returns `true` if the items were copied. If `span` extends past the end of the collection,
+	/// returns `false` without modifying `register`.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if `span` was created by this cursor, and the
+	/// collection has been structurally edited since.
+	pub fn copy_to_register(&self, span: CursorSpan, register: &mut Register<Tape::Item>) -> bool {
+		#[cfg(feature = "debug-invariants")]
+		self.assert_span_valid(&span);
+
+		if span.end() > self.inner.len() {
+			return false;
+		}
+
+		register.items.clear();
+		register.items.extend(span.iter(self).cloned());
+		true
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
+	/// Removes the items within `span`, replacing the contents of `register` with them.
+	///
+	/// If the cursor was after `span`, it is moved backwards so that it keeps pointing at the same
+	/// item. If it was within `span`, it is moved to the item which followed the removed items.
+	/// The checkpoints and marks are adjusted in the same way.
+	///
+	/// Returns `true` if the items were cut. If `span` extends past the end of the collection,
+	/// returns `false` without modifying the collection or `register`.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if `span` was created by this cursor, and the
+	/// collection has been structurally edited since.
+	///
+	/// # Performance
+	/// Items are removed one at a time from the start of `span`, so this may be slow for
+	/// collections where removing from the middle is expensive.
+	pub fn cut_to_register(
+		&mut self,
+		span: CursorSpan,
+		register: &mut Register<Tape::Item>,
+	) -> bool {
+		#[cfg(feature = "debug-invariants")]
+		self.assert_span_valid(&span);
+
+		let (start, end) = (span.start(), span.end());
+		if end > self.inner.len() {
+			return false;
+		}
+
+		register.items.clear();
+		for _ in start..end {
+			if let Some(item) = self.inner.remove_item(start) {
+				register.items.push(item);
+			}
+		}
+
+		if self.pos >= end {
+			self.pos -= span.len();
+		} else if self.pos > start {
+			self.pos = start;
+		}
+		self.shift_saved_positions_for_remove(start, span.len());
+		true
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Inserts clones of the items held by `register` at the cursor, keeping their order and
+	/// shifting the following items to the right. The cursor is left pointing at the first
+	/// inserted item (or is not moved, if `register` is empty), and `register` is left as-is, so
+	/// it may be pasted again.
+	///
+	/// Returns the span the inserted items occupy.
+	///
+	/// # Panics
+	/// Panics if `self.position() > self.get_ref().len()`, or if an insert operation panics.
+	pub fn paste_from_register(&mut self, register: &Register<Tape::Item>) -> CursorSpan {
+		assert!(
+			self.pos <= self.inner.len(),
+			"cursor position should be within the bounds of the collection"
+		);
+
+		for (offset, item) in register.items.iter().enumerate() {
+			self.inner.insert_item(self.pos + offset, item.clone());
+		}
+		self.shift_saved_positions_for_insert(self.pos, register.len());
+		self.span_between(self.pos, self.pos + register.len())
+	}
+}
+
+#[cfg(test)]
+mod register_tests {
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use super::*;
+
+	#[test]
+	fn copy_and_paste_between_cursors() {
+		let source = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		let mut destination = CollectionCursor::new(VecDeque::from([10, 11, 12]));
+		let mut register = Register::new();
+
+		assert!(source.copy_to_register(CursorSpan::new(1, 4), &mut register));
+		assert_eq!(register.items(), [1, 2, 3]);
+		assert_eq!(
+			*source.get_ref(),
+			[0, 1, 2, 3, 4, 5],
+			"shouldn't modify the source"
+		);
+		assert!(
+			!source.copy_to_register(CursorSpan::new(4, 7), &mut register),
+			"shouldn't copy a span past the end"
+		);
+		assert_eq!(
+			register.items(),
+			[1, 2, 3],
+			"shouldn't modify the register on failure"
+		);
+
+		destination.seek_relative(1).unwrap();
+		destination.set_mark("eleven");
+		assert_eq!(
+			destination.paste_from_register(&register),
+			CursorSpan::new(1, 4)
+		);
+		assert_eq!(*destination.get_ref(), [10, 1, 2, 3, 11, 12]);
+		assert_eq!(
+			destination.position(),
+			1,
+			"should point at the first pasted item"
+		);
+		assert_eq!(destination.mark("eleven"), Some(4), "should shift marks");
+	}
+
+	#[test]
+	fn cut_to_register() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		let mut register = Register::from(Vec::from([9]));
+		cursor.seek_relative(5).unwrap();
+		cursor.save_checkpoint();
+
+		assert!(cursor.cut_to_register(CursorSpan::new(1, 3), &mut register));
+		assert_eq!(
+			register.items(),
+			[1, 2],
+			"should replace the register's contents"
+		);
+		assert_eq!(*cursor.get_ref(), [0, 3, 4, 5]);
+		assert_eq!(
+			cursor.position(),
+			3,
+			"should keep pointing at the same item"
+		);
+		assert_eq!(cursor.checkpoint(), Some(3));
+
+		cursor.seek_relative(-1).unwrap();
+		assert!(cursor.cut_to_register(CursorSpan::new(1, 4), &mut register));
+		assert_eq!(*cursor.get_ref(), [0]);
+		assert_eq!(cursor.position(), 1, "should move out of the removed items");
+		assert!(!cursor.cut_to_register(CursorSpan::new(0, 2), &mut register));
+		assert_eq!(register.items(), [3, 4, 5]);
+	}
+}
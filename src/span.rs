@@ -0,0 +1,371 @@
+use core::ops::Range;
+
+#[cfg(feature = "debug-invariants")]
+use crate::ignored::Ignored;
+use crate::{CollectionCursor, IndexableCollection, IndexableCollectionResizable, Iter};
+
+/// A range of positions within a cursor's collection, from `start` up to (but not including)
+/// `end`.
+///
+/// This is usually created by [`CollectionCursor::span_from()`], to record which items the cursor
+/// moved past - for example, the source span of a token.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CursorSpan {
+	/// The first position within the span.
+	start: usize,
+	/// The position just after the last position within the span. Always `>= start`.
+	end: usize,
+	/// The generation of the collection when the span was created by a cursor.
+	#[cfg(feature = "debug-invariants")]
+	#[cfg_attr(feature = "serde", serde(skip))]
+	pub(crate) generation: Ignored<Option<u64>>,
+}
+
+impl CursorSpan {
+	/// Creates a span between two positions. The positions may be given in either order.
+	pub const fn new(a: usize, b: usize) -> Self {
+		let (start, end) = if a <= b { (a, b) } else { (b, a) };
+		Self {
+			start,
+			end,
+			#[cfg(feature = "debug-invariants")]
+			generation: Ignored(None),
+		}
+	}
+
+	/// Returns the first position within the span.
+	pub const fn start(&self) -> usize {
+		self.start
+	}
+
+	/// Returns the position just after the last position within the span.
+	pub const fn end(&self) -> usize {
+		self.end
+	}
+
+	/// Returns the number of positions within the span.
+	pub const fn len(&self) -> usize {
+		self.end - self.start
+	}
+
+	/// Returns whether the span contains no positions.
+	pub const fn is_empty(&self) -> bool {
+		self.start == self.end
+	}
+
+	/// Returns whether `pos` is within the span.
+	pub const fn contains(&self, pos: usize) -> bool {
+		self.start <= pos && pos < self.end
+	}
+
+	/// Returns the span as a range of positions.
+	pub const fn range(&self) -> Range<usize> {
+		self.start..self.end
+	}
+
+	/// Returns an iterator over the items of `cursor`'s collection which are within the span.
+	///
+	/// Any part of the span past the end of the collection is ignored.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if the span was created by `cursor`, and the
+	/// collection has been structurally edited since.
+	pub fn iter<'a, Tape: IndexableCollection>(
+		&self,
+		cursor: &'a CollectionCursor<Tape>,
+	) -> Iter<'a, Tape> {
+		#[cfg(feature = "debug-invariants")]
+		cursor.assert_span_valid(self);
+
+		Iter::new(&cursor.inner, self.start, self.end)
+	}
+}
+
+impl From<Range<usize>> for CursorSpan {
+	fn from(range: Range<usize>) -> Self {
+		Self::new(range.start, range.end)
+	}
+}
+
+impl From<CursorSpan> for Range<usize> {
+	fn from(span: CursorSpan) -> Self {
+		span.range()
+	}
+}
+
+impl<Tape> CollectionCursor<Tape> {
+	/// Returns the span between `saved_pos` and the cursor's current position.
+	///
+	/// This is typically used by saving the position before reading something, then calling this
+	/// to get the span of the items which were read.
+	pub fn span_from(&self, saved_pos: usize) -> CursorSpan {
+		self.span_between(saved_pos, self.pos)
+	}
+
+	/// Returns the span between the marks named `a` and `b`, or `None` if either mark does not
+	/// exist. See [`Self::set_mark()`].
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if the collection was edited outside of the
+	/// cursor since the marks were set.
+	#[cfg(feature = "alloc")]
+	pub fn span_between_marks(&self, a: &str, b: &str) -> Option<CursorSpan> {
+		#[cfg(feature = "debug-invariants")]
+		self.assert_saved_positions_valid();
+
+		Some(self.span_between(self.mark(a)?, self.mark(b)?))
+	}
+
+	/// Creates a span between two positions. With the `debug-invariants` feature, the span
+	/// remembers the collection's current generation.
+	pub(crate) fn span_between(&self, a: usize, b: usize) -> CursorSpan {
+		CursorSpan {
+			#[cfg(feature = "debug-invariants")]
+			generation: Ignored(Some(self.generation.current())),
+			..CursorSpan::new(a, b)
+		}
+	}
+}
+
+impl<Tape: IndexableCollection> CollectionCursor<Tape>
+where
+	Tape::Item: Clone,
+{
+	/// Clones the items within `span` into a new collection of type `C`, keeping their order. The
+	/// cursor and the inner collection are left untouched.
+	///
+	/// Any part of the span past the end of the collection is ignored.
+	pub fn clone_span<C: FromIterator<Tape::Item>>(&self, span: CursorSpan) -> C {
+		span.iter(self).cloned().collect()
+	}
+}
+
+impl<Tape: IndexableCollectionResizable> CollectionCursor<Tape> {
+	/// Removes the items within `span`, and reinserts them at the cursor, keeping their order. The
+	/// cursor is left pointing at the first moved item.
+	///
+	/// The checkpoints and marks are adjusted so that they keep pointing at the same items. If the
+	/// cursor is within `span` (or at its end), the items are already at the cursor, so only the
+	/// cursor is moved.
+	///
+	/// Returns `Some(new_span)`, where `new_span` is the span the moved items now occupy, or `None`
+	/// if `span` extends past the end of the collection. In the latter case, the collection is not
+	/// modified and the cursor is not moved.
+	///
+	/// With the `metrics` feature, the moved items are counted as both removed and inserted.
+	///
+	/// # Performance
+	/// The items are moved with [`IndexableCollectionResizable::rotate_items_left()`], which is a
+	/// single [`slice::rotate_left()`] for contiguous collections such as `Vec`. Other collections
+	/// move whichever is smaller of `span` and the items between `span` and the cursor, one item
+	/// at a time.
+	///
+	/// # Panics
+	/// With the `debug-invariants` feature, panics if `span` was created by this cursor, and the
+	/// collection has been structurally edited since.
+	pub fn move_range_to_cursor(&mut self, span: CursorSpan) -> Option<CursorSpan> {
+		#[cfg(feature = "debug-invariants")]
+		self.assert_span_valid(&span);
+
+		let (start, end) = (span.start(), span.end());
+		if end > self.inner.len() {
+			return None;
+		}
+
+		let span_len = span.len();
+		let cursor = self.pos;
+		let new_start = if cursor < start {
+			// The items between the cursor and the span shift right, past the span.
+			self.inner.rotate_items_left(cursor..end, start - cursor);
+			self.adjust_saved_positions(|saved| {
+				if (start..end).contains(&saved) {
+					saved - start + cursor
+				} else if (cursor..start).contains(&saved) {
+					saved + span_len
+				} else {
+					saved
+				}
+			});
+			cursor
+		} else if cursor > end {
+			// The items between the span and the cursor shift left, before the span.
+			self.inner.rotate_items_left(start..cursor, span_len);
+			let new_start = cursor - span_len;
+			self.adjust_saved_positions(|saved| {
+				if (start..end).contains(&saved) {
+					saved - start + new_start
+				} else if (end..cursor).contains(&saved) {
+					saved - span_len
+				} else {
+					saved
+				}
+			});
+			new_start
+		} else {
+			start
+		};
+
+		if new_start != start {
+			self.record_removals(span_len);
+			self.record_inserts(span_len);
+			#[cfg(feature = "alloc")]
+			self.notify_edit(crate::CursorEventKind::Reorder {
+				start: new_start.min(start),
+				end: new_start.max(start) + span_len,
+			});
+		}
+		self.pos = new_start;
+		self.trace_edit(format_args!(
+			"moved the items at indices {start}..{end} to index {new_start}"
+		));
+		Some(self.span_between(new_start, new_start + span_len))
+	}
+}
+
+#[cfg(test)]
+mod span_tests {
+	extern crate alloc;
+
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use super::*;
+
+	#[test]
+	fn span() {
+		let span = CursorSpan::new(5, 2);
+		assert_eq!(span.start(), 2, "should order the positions");
+		assert_eq!(span.end(), 5);
+		assert_eq!(span.len(), 3);
+		assert!(!span.is_empty());
+		assert!(span.contains(2));
+		assert!(span.contains(4));
+		assert!(!span.contains(5), "shouldn't contain the end position");
+		assert_eq!(span.range(), 2..5);
+		assert_eq!(CursorSpan::from(2..5), span);
+		assert!(CursorSpan::new(3, 3).is_empty());
+	}
+
+	#[test]
+	#[cfg(feature = "core")]
+	fn span_from() {
+		let mut cursor = CollectionCursor::new(*b"let x = 1;");
+		cursor.skip_while(u8::is_ascii_alphabetic);
+		cursor.skip_while(u8::is_ascii_whitespace);
+
+		let start = cursor.position();
+		cursor.skip_while(u8::is_ascii_alphabetic);
+		let span = cursor.span_from(start);
+		assert_eq!(span.range(), 4..5);
+		assert!(span.iter(&cursor).eq(b"x"));
+
+		let span = CursorSpan::new(8, 20);
+		assert!(
+			span.iter(&cursor).eq(b"1;"),
+			"should ignore positions past the end"
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn span_between_marks() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_relative(4).unwrap();
+		cursor.set_mark("end");
+		cursor.seek_relative(-3).unwrap();
+		cursor.set_mark("start");
+
+		let span = cursor
+			.span_between_marks("start", "end")
+			.expect("both marks should exist");
+		assert_eq!(span.range(), 1..4);
+		assert!(span.iter(&cursor).copied().eq([1, 2, 3]));
+		assert_eq!(cursor.span_between_marks("start", "missing"), None);
+	}
+
+	#[test]
+	fn clone_span() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5]));
+		cursor.seek_relative(4).unwrap();
+
+		let items: Vec<i32> = cursor.clone_span(cursor.span_from(1));
+		assert_eq!(items, [1, 2, 3]);
+		assert_eq!(
+			*cursor.get_ref(),
+			[0, 1, 2, 3, 4, 5],
+			"shouldn't modify the collection"
+		);
+		assert_eq!(cursor.position(), 4, "shouldn't move the cursor");
+
+		let items: Vec<i32> = cursor.clone_span(CursorSpan::new(5, 10));
+		assert_eq!(items, [5], "should ignore positions past the end");
+	}
+
+	#[test]
+	fn move_range_to_cursor() {
+		let mut cursor = CollectionCursor::new(VecDeque::from([0, 1, 2, 3, 4, 5, 6, 7]));
+		cursor.seek_relative(1).unwrap();
+		cursor.save_checkpoint();
+
+		assert_eq!(
+			cursor.move_range_to_cursor(CursorSpan::new(5, 7)),
+			Some(CursorSpan::new(1, 3)),
+			"should move items backwards"
+		);
+		assert_eq!(*cursor.get_ref(), [0, 5, 6, 1, 2, 3, 4, 7]);
+		assert_eq!(cursor.position(), 1, "should point at the first moved item");
+		assert_eq!(
+			cursor.checkpoint(),
+			Some(3),
+			"should keep the checkpoint on its item"
+		);
+
+		cursor.seek_to_end();
+		assert_eq!(
+			cursor.move_range_to_cursor(CursorSpan::new(0, 3)),
+			Some(CursorSpan::new(5, 8)),
+			"should move items forwards"
+		);
+		assert_eq!(*cursor.get_ref(), [1, 2, 3, 4, 7, 0, 5, 6]);
+		assert_eq!(cursor.position(), 5);
+		assert_eq!(cursor.checkpoint(), Some(0));
+
+		cursor.seek_relative(-1).unwrap();
+		assert_eq!(
+			cursor.move_range_to_cursor(CursorSpan::new(2, 4)),
+			Some(CursorSpan::new(2, 4)),
+			"shouldn't move items when the cursor is at the end of the span"
+		);
+		assert_eq!(*cursor.get_ref(), [1, 2, 3, 4, 7, 0, 5, 6]);
+		assert_eq!(cursor.position(), 2);
+
+		assert_eq!(cursor.move_range_to_cursor(CursorSpan::new(6, 9)), None);
+		assert_eq!(cursor.position(), 2, "shouldn't move for an invalid span");
+	}
+
+	#[test]
+	fn move_range_to_cursor_contiguous() {
+		let mut cursor = CollectionCursor::new(Vec::from([0, 1, 2, 3, 4, 5, 6, 7]));
+		cursor.seek_relative(1).unwrap();
+
+		assert_eq!(
+			cursor.move_range_to_cursor(CursorSpan::new(5, 7)),
+			Some(CursorSpan::new(1, 3))
+		);
+		assert_eq!(*cursor.get_ref(), [0, 5, 6, 1, 2, 3, 4, 7]);
+
+		cursor.seek_to_end();
+		assert_eq!(
+			cursor.move_range_to_cursor(CursorSpan::new(0, 3)),
+			Some(CursorSpan::new(5, 8))
+		);
+		assert_eq!(*cursor.get_ref(), [1, 2, 3, 4, 7, 0, 5, 6]);
+
+		#[cfg(feature = "metrics")]
+		{
+			let metrics = cursor.metrics();
+			assert_eq!(metrics.removals(), 5, "should count each moved item");
+			assert_eq!(metrics.inserts(), 5);
+		}
+	}
+}
@@ -0,0 +1,320 @@
+//! Utilities for checking that your own implementations of this crate's traits behave the way
+//! [`CollectionCursor`] expects.
+
+extern crate alloc;
+
+use core::{fmt::Debug, ptr};
+
+use alloc::vec::Vec;
+
+use crate::{
+	CollectionCursor, ContiguousCollection, IndexableCollection, IndexableCollectionResizable,
+	SeekFrom,
+};
+
+/// The seeds used for each run of [`assert_cursor_invariants()`]. Each seed produces a different
+/// (but always the same) sequence of operations.
+const SEEDS: [u64; 4] = [
+	0x9E37_79B9_7F4A_7C15,
+	0xD1B5_4A32_D192_ED03,
+	0xAEF1_7502_108E_F2D9,
+	0x2545_F491_4F6C_DD1D,
+];
+
+/// The number of operations performed for each seed.
+const OPERATIONS_PER_RUN: usize = 512;
+
+/// The most items [`assert_cursor_invariants()`] will grow a collection by, beyond the length of
+/// the collection it was given. This allows the check to be used with fixed-capacity collections.
+pub const MAX_GROWTH: usize = 8;
+
+/// Runs a battery of seek, set, insert, remove, and clear operations against cursors wrapping
+/// collections returned by `make`, and checks that:
+///
+/// * `0 <= cursor.position() <= cursor.get_ref().len()` holds after every operation
+/// * the cursor and collection behave identically to a cursor over a `Vec` holding the same items
+///
+/// The operations are chosen by a small deterministic pseudo-random number generator, so any
+/// failure will be reproducible. `make` is called once per run, and may return a collection which
+/// already contains items. The collection will never grow by more than [`MAX_GROWTH`] items past
+/// its initial length.
+///
+/// # Panics
+/// Panics if any of the above checks fail. The panic message will describe the check which failed,
+/// along with the seed and operation number it failed on.
+pub fn assert_cursor_invariants<T, F>(make: F)
+where
+	T: IndexableCollectionResizable,
+	T::Item: From<u8> + Clone + PartialEq + Debug,
+	F: Fn() -> T,
+{
+	for seed in SEEDS {
+		self::run(make(), seed);
+	}
+}
+
+/// Performs a single run of [`assert_cursor_invariants()`] using the provided seed.
+fn run<T>(collection: T, seed: u64)
+where
+	T: IndexableCollectionResizable,
+	T::Item: From<u8> + Clone + PartialEq + Debug,
+{
+	let mut reference = Vec::with_capacity(collection.len() + MAX_GROWTH);
+	reference.extend(
+		(0..collection.len()).map(|index| match collection.get_item(index) {
+			Some(item) => item.clone(),
+			None => panic!("`get_item({index})` returned `None` despite being less than `len()`"),
+		}),
+	);
+	let max_len = reference.len() + MAX_GROWTH;
+
+	let mut cursor = CollectionCursor::new(collection);
+	let mut reference_pos = 0;
+	let mut rng = Xorshift(seed);
+
+	for operation in 0..OPERATIONS_PER_RUN {
+		let context = Context { seed, operation };
+
+		match rng.below(8) {
+			0 => {
+				let seek_from = rng.seek_from(reference.len());
+				let expected = expected_seek(seek_from, reference_pos, reference.len());
+				let actual = cursor.seek(seek_from).ok();
+				context.assert_eq(&actual, &expected, "`seek()` returned a different result");
+				if let Some(new_pos) = expected {
+					reference_pos = new_pos;
+				}
+			}
+			1 => {
+				let expected = reference_pos < reference.len();
+				let actual = cursor.seek_forward_one();
+				context.assert_eq(
+					&actual,
+					&expected,
+					"`seek_forward_one()` returned a different result",
+				);
+				reference_pos += usize::from(expected);
+			}
+			2 => {
+				let expected = reference_pos > 0;
+				let actual = cursor.seek_backward_one();
+				context.assert_eq(
+					&actual,
+					&expected,
+					"`seek_backward_one()` returned a different result",
+				);
+				reference_pos -= usize::from(expected);
+			}
+			3 if reference.len() < max_len => {
+				let item = T::Item::from(rng.byte());
+				cursor.insert_item_at_cursor(item.clone());
+				reference.insert(reference_pos, item);
+			}
+			4 => {
+				let expected =
+					(reference_pos < reference.len()).then(|| reference.remove(reference_pos));
+				let actual = cursor.remove_item_at_cursor();
+				context.assert_eq(
+					&actual,
+					&expected,
+					"`remove_item_at_cursor()` returned a different item",
+				);
+			}
+			5 if reference_pos < reference.len() => {
+				let item = T::Item::from(rng.byte());
+				cursor.set_item_at_cursor(item.clone());
+				reference[reference_pos] = item;
+			}
+			6 => {
+				cursor.clamp_to_last_item();
+				reference_pos = reference_pos.min(reference.len().saturating_sub(1));
+			}
+			// Clearing throws away all the state we've built up, so only do it occasionally.
+			7 if rng.below(8) == 0 => {
+				cursor.clear();
+				reference.clear();
+				reference_pos = 0;
+			}
+			_ => {}
+		}
+
+		context.assert_consistent(&cursor, &reference, reference_pos);
+	}
+}
+
+/// Checks that the [`ContiguousCollection`] implementation of `collection` agrees with its
+/// [`IndexableCollection`] implementation, and that the cursor's slice methods work over it:
+///
+/// * `as_slice()` and `as_mut_slice()` contain `len()` items, which are the same items (not just
+///   equal ones) returned by `get_item()`
+/// * [`CollectionCursor::split_at_cursor_ref()`] and [`CollectionCursor::remaining_slice_mut()`]
+///   split the items at every position of the cursor
+///
+/// # Panics
+/// Panics if any of the above checks fail.
+pub fn assert_contiguous<T: ContiguousCollection>(collection: T) {
+	// The collection is moved into the cursor first, as moving it may move any inline items.
+	let mut cursor = CollectionCursor::new(collection);
+	let collection = cursor.get_ref();
+	let len = collection.len();
+	let slice = collection.as_slice();
+	assert_eq!(
+		slice.len(),
+		len,
+		"`as_slice()` should contain `len()` items"
+	);
+	for (index, item) in slice.iter().enumerate() {
+		assert!(
+			collection
+				.get_item(index)
+				.is_some_and(|expected| ptr::eq(item, expected)),
+			"`as_slice()[{index}]` should be the item returned by `get_item({index})`"
+		);
+	}
+	let slice_ptr = slice.as_ptr();
+
+	for pos in 0..=len {
+		cursor.pos = pos;
+
+		let (before, after) = cursor.split_at_cursor_ref();
+		assert_eq!(
+			(before.len(), after.len()),
+			(pos, len - pos),
+			"`split_at_cursor_ref()` should split the items at position {pos}"
+		);
+
+		let remaining = cursor.remaining_slice_mut();
+		assert_eq!(
+			remaining.len(),
+			len - pos,
+			"`remaining_slice_mut()` should contain the items from position {pos} onwards"
+		);
+		assert!(
+			ptr::eq(remaining.as_ptr(), slice_ptr.wrapping_add(pos)),
+			"`as_mut_slice()` should view the same items as `as_slice()`"
+		);
+	}
+}
+
+/// Computes the result that [`CollectionCursor::seek()`] should return.
+fn expected_seek(seek_from: SeekFrom, pos: usize, len: usize) -> Option<usize> {
+	let target = match seek_from {
+		SeekFrom::Start(p) => i128::try_from(p).ok()?,
+		SeekFrom::End(p) => i128::try_from(len).ok()? + i128::try_from(p).ok()?,
+		SeekFrom::Current(p) => i128::try_from(pos).ok()? + i128::try_from(p).ok()?,
+	};
+
+	usize::try_from(target).ok().filter(|&target| target <= len)
+}
+
+/// Identifies the operation being checked, for use in panic messages.
+#[derive(Clone, Copy)]
+struct Context {
+	seed: u64,
+	operation: usize,
+}
+
+impl Context {
+	#[track_caller]
+	fn assert_eq<V: PartialEq + Debug>(&self, actual: &V, expected: &V, message: &str) {
+		assert_eq!(
+			actual, expected,
+			"{message} (seed = `{:#x}`, operation = `{}`)",
+			self.seed, self.operation
+		);
+	}
+
+	#[track_caller]
+	fn assert_consistent<T>(&self, cursor: &CollectionCursor<T>, reference: &[T::Item], pos: usize)
+	where
+		T: IndexableCollection,
+		T::Item: PartialEq + Debug,
+	{
+		if let Err(error) = cursor.check_invariant() {
+			panic!(
+				"{error} (seed = `{:#x}`, operation = `{}`)",
+				self.seed, self.operation
+			);
+		}
+
+		let collection = cursor.get_ref();
+		self.assert_eq(
+			&cursor.position(),
+			&pos,
+			"the cursor was not at the expected position",
+		);
+		self.assert_eq(
+			&collection.len(),
+			&reference.len(),
+			"the collection had an unexpected length",
+		);
+
+		// We deliberately request one item past the end, to check that it returns `None`
+		for index in 0..=reference.len() {
+			self.assert_eq(
+				&collection.get_item(index),
+				&reference.get(index),
+				"the collection did not contain the expected item",
+			);
+		}
+	}
+}
+
+/// A small deterministic pseudo-random number generator (xorshift64), so that this module works
+/// without any additional dependencies.
+struct Xorshift(u64);
+
+impl Xorshift {
+	fn next(&mut self) -> u64 {
+		self.0 ^= self.0 << 13;
+		self.0 ^= self.0 >> 7;
+		self.0 ^= self.0 << 17;
+		self.0
+	}
+
+	/// Returns a number in the range `0..n`.
+	fn below(&mut self, n: usize) -> usize {
+		// `n` is always small, so the slight bias from using modulo doesn't matter here.
+		(self.next() % n as u64) as usize
+	}
+
+	fn byte(&mut self) -> u8 {
+		self.next().to_le_bytes()[0]
+	}
+
+	/// Returns a `SeekFrom` which lands somewhere around the bounds of a collection of length
+	/// `len`, so that both successful and failing seeks are exercised.
+	fn seek_from(&mut self, len: usize) -> SeekFrom {
+		let span = len + 4;
+		let offset = self.below(span * 2).cast_signed() - span.cast_signed();
+
+		match self.below(3) {
+			0 => SeekFrom::Start(self.below(span)),
+			1 => SeekFrom::End(offset),
+			_ => SeekFrom::Current(offset),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test_util_tests {
+	extern crate alloc;
+
+	use alloc::{collections::VecDeque, vec::Vec};
+
+	use super::{assert_contiguous, assert_cursor_invariants};
+
+	#[test]
+	fn vec() {
+		assert_cursor_invariants(Vec::<i32>::new);
+		assert_cursor_invariants(|| Vec::<i32>::from([5, 4, 3, 2, 1]));
+		assert_contiguous(Vec::<i32>::new());
+		assert_contiguous(Vec::from([5, 4, 3, 2, 1]));
+	}
+
+	#[test]
+	fn vec_deque() {
+		assert_cursor_invariants(VecDeque::<u8>::new);
+		assert_cursor_invariants(|| VecDeque::<u8>::from([5, 4, 3, 2, 1]));
+	}
+}
@@ -2,7 +2,10 @@ extern crate alloc;
 
 use alloc::{collections::VecDeque, vec::Vec};
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection, DequeCollection, IndexableCollection, IndexableCollectionMut,
+	IndexableCollectionResizable,
+};
 
 impl<T> IndexableCollection for Vec<T> {
 	type Item = T;
@@ -14,7 +17,11 @@ impl<T> IndexableCollectionMut for Vec<T> {
 }
 
 impl<T> IndexableCollectionResizable for Vec<T> {
-	forward_resizable!(check_len_on_remove = true);
+	forward_resizable!(check_len_on_remove = true, contiguous);
+}
+
+impl<T> ContiguousCollection for Vec<T> {
+	forward_contiguous!();
 }
 
 impl<T> IndexableCollection for VecDeque<T> {
@@ -29,3 +36,7 @@ impl<T> IndexableCollectionMut for VecDeque<T> {
 impl<T> IndexableCollectionResizable for VecDeque<T> {
 	forward_resizable!(check_len_on_remove = false);
 }
+
+impl<T> DequeCollection for VecDeque<T> {
+	forward_deque!();
+}
@@ -1,6 +1,8 @@
 use arrayvec::ArrayVec;
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection, IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable,
+};
 
 impl<T, const CAP: usize> IndexableCollection for ArrayVec<T, CAP> {
 	type Item = T;
@@ -12,5 +14,26 @@ impl<T, const CAP: usize> IndexableCollectionMut for ArrayVec<T, CAP> {
 }
 
 impl<T, const CAP: usize> IndexableCollectionResizable for ArrayVec<T, CAP> {
-	forward_resizable!(check_len_on_remove = true);
+	forward_resizable!(check_len_on_remove = true, contiguous);
+
+	fn remaining_capacity(&self) -> Option<usize> {
+		Some(ArrayVec::remaining_capacity(self))
+	}
+}
+
+impl<T, const CAP: usize> ContiguousCollection for ArrayVec<T, CAP> {
+	forward_contiguous!();
+}
+
+#[cfg(test)]
+mod arrayvec_tests {
+	use arrayvec::ArrayVec;
+
+	use crate::test_util::assert_contiguous;
+
+	#[test]
+	fn contiguous_array_vec() {
+		assert_contiguous(ArrayVec::from([0, 1, 2, 3]));
+		assert_contiguous(ArrayVec::<i32, 4>::new());
+	}
 }
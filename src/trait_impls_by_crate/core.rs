@@ -1,4 +1,4 @@
-use crate::{IndexableCollection, IndexableCollectionMut};
+use crate::{ContiguousCollection, IndexableCollection, IndexableCollectionMut};
 
 impl<T, const N: usize> IndexableCollection for [T; N] {
 	type Item = T;
@@ -13,3 +13,41 @@ impl<T, const N: usize> IndexableCollection for [T; N] {
 impl<T, const N: usize> IndexableCollectionMut for [T; N] {
 	forward_mutable!();
 }
+
+impl<T, const N: usize> ContiguousCollection for [T; N] {
+	forward_contiguous!();
+}
+
+// The impls for `&mut [T]` call the slice methods by path, as calling them by method syntax would
+// resolve to the trait methods being defined here.
+impl<T> IndexableCollection for &mut [T] {
+	type Item = T;
+
+	fn len(&self) -> usize {
+		<[T]>::len(self)
+	}
+
+	fn get_item(&self, index: usize) -> Option<&Self::Item> {
+		<[T]>::get(self, index)
+	}
+}
+
+impl<T> IndexableCollectionMut for &mut [T] {
+	fn get_item_mut(&mut self, index: usize) -> Option<&mut Self::Item> {
+		<[T]>::get_mut(self, index)
+	}
+
+	fn set_item(&mut self, index: usize, element: Self::Item) {
+		self[index] = element;
+	}
+}
+
+impl<T> ContiguousCollection for &mut [T] {
+	fn as_slice(&self) -> &[Self::Item] {
+		self
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+		self
+	}
+}
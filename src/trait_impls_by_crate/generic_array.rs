@@ -1,6 +1,6 @@
 use generic_array::{ArrayLength, GenericArray};
 
-use crate::{IndexableCollection, IndexableCollectionMut};
+use crate::{ContiguousCollection, IndexableCollection, IndexableCollectionMut};
 
 impl<T, N: ArrayLength> IndexableCollection for GenericArray<T, N> {
 	type Item = T;
@@ -15,3 +15,19 @@ impl<T, N: ArrayLength> IndexableCollection for GenericArray<T, N> {
 impl<T, N: ArrayLength> IndexableCollectionMut for GenericArray<T, N> {
 	forward_mutable!();
 }
+
+impl<T, N: ArrayLength> ContiguousCollection for GenericArray<T, N> {
+	forward_contiguous!();
+}
+
+#[cfg(test)]
+mod generic_array_tests {
+	use generic_array::GenericArray;
+
+	use crate::test_util::assert_contiguous;
+
+	#[test]
+	fn contiguous_generic_array() {
+		assert_contiguous(GenericArray::from_array([0, 1, 2, 3]));
+	}
+}
@@ -32,6 +32,10 @@ macro_rules! forward_resizable {
 		forward_resizable!(__inner, main);
 		forward_resizable!(__inner, remove, check_len = $check_len);
 	};
+	(check_len_on_remove = $check_len:tt, contiguous) => {
+		forward_resizable!(check_len_on_remove = $check_len);
+		forward_resizable!(__inner, rotate);
+	};
 	(__inner, main) => {
 		fn insert_item(&mut self, index: usize, element: Self::Item) {
 			self.insert(index, element);
@@ -41,6 +45,11 @@ macro_rules! forward_resizable {
 			self.clear();
 		}
 	};
+	(__inner, rotate) => {
+		fn rotate_items_left(&mut self, range: core::ops::Range<usize>, mid: usize) {
+			self.as_mut_slice()[range].rotate_left(mid);
+		}
+	};
 	(__inner, remove, check_len = true) => {
 		fn remove_item(&mut self, index: usize) -> Option<Self::Item> {
 			(index < self.len()).then(|| self.remove(index))
@@ -53,6 +62,40 @@ macro_rules! forward_resizable {
 	};
 }
 
+macro_rules! forward_contiguous {
+	() => {
+		fn as_slice(&self) -> &[Self::Item] {
+			self.as_slice()
+		}
+
+		fn as_mut_slice(&mut self) -> &mut [Self::Item] {
+			self.as_mut_slice()
+		}
+	};
+}
+
+// Only `VecDeque` implements `DequeCollection` so far.
+#[cfg(feature = "alloc")]
+macro_rules! forward_deque {
+	() => {
+		fn push_front(&mut self, element: Self::Item) {
+			self.push_front(element);
+		}
+
+		fn push_back(&mut self, element: Self::Item) {
+			self.push_back(element);
+		}
+
+		fn pop_front(&mut self) -> Option<Self::Item> {
+			self.pop_front()
+		}
+
+		fn pop_back(&mut self) -> Option<Self::Item> {
+			self.pop_back()
+		}
+	};
+}
+
 /// Tests against `forward_ref!()` and `forward_mut!()`
 #[cfg(test)]
 mod forward_macro_tests {
@@ -1,6 +1,8 @@
 use smallvec::{Array, SmallVec};
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection, IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable,
+};
 
 impl<A: Array> IndexableCollection for SmallVec<A> {
 	type Item = <A as Array>::Item;
@@ -12,5 +14,23 @@ impl<A: Array> IndexableCollectionMut for SmallVec<A> {
 }
 
 impl<A: Array> IndexableCollectionResizable for SmallVec<A> {
-	forward_resizable!(check_len_on_remove = true);
+	forward_resizable!(check_len_on_remove = true, contiguous);
+}
+
+impl<A: Array> ContiguousCollection for SmallVec<A> {
+	forward_contiguous!();
+}
+
+#[cfg(test)]
+mod smallvec_tests {
+	use smallvec::SmallVec;
+
+	use crate::test_util::assert_contiguous;
+
+	#[test]
+	fn contiguous_small_vec() {
+		assert_contiguous(SmallVec::<[i32; 4]>::from_slice(&[0, 1, 2]));
+		// Holds more items than fit inline, so they spill onto the heap.
+		assert_contiguous(SmallVec::<[i32; 2]>::from_slice(&[0, 1, 2, 3]));
+	}
 }
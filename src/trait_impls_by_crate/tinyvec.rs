@@ -2,7 +2,9 @@
 use tinyvec::TinyVec;
 use tinyvec::{Array, ArrayVec, SliceVec};
 
-use crate::{IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable};
+use crate::{
+	ContiguousCollection, IndexableCollection, IndexableCollectionMut, IndexableCollectionResizable,
+};
 
 impl<A: Array> IndexableCollection for ArrayVec<A> {
 	type Item = <A as Array>::Item;
@@ -14,7 +16,15 @@ impl<A: Array> IndexableCollectionMut for ArrayVec<A> {
 }
 
 impl<A: Array> IndexableCollectionResizable for ArrayVec<A> {
-	forward_resizable!(check_len_on_remove = true);
+	forward_resizable!(check_len_on_remove = true, contiguous);
+
+	fn remaining_capacity(&self) -> Option<usize> {
+		Some(self.capacity() - self.len())
+	}
+}
+
+impl<A: Array> ContiguousCollection for ArrayVec<A> {
+	forward_contiguous!();
 }
 
 impl<'s, T> IndexableCollection for SliceVec<'s, T> {
@@ -27,7 +37,15 @@ impl<'s, T> IndexableCollectionMut for SliceVec<'s, T> {
 }
 
 impl<'s, T: Default> IndexableCollectionResizable for SliceVec<'s, T> {
-	forward_resizable!(check_len_on_remove = true);
+	forward_resizable!(check_len_on_remove = true, contiguous);
+
+	fn remaining_capacity(&self) -> Option<usize> {
+		Some(self.capacity() - self.len())
+	}
+}
+
+impl<'s, T> ContiguousCollection for SliceVec<'s, T> {
+	forward_contiguous!();
 }
 
 #[cfg(feature = "alloc")]
@@ -43,5 +61,36 @@ impl<A: Array> IndexableCollectionMut for TinyVec<A> {
 
 #[cfg(feature = "alloc")]
 impl<A: Array> IndexableCollectionResizable for TinyVec<A> {
-	forward_resizable!(check_len_on_remove = true);
+	forward_resizable!(check_len_on_remove = true, contiguous);
+}
+
+#[cfg(feature = "alloc")]
+impl<A: Array> ContiguousCollection for TinyVec<A> {
+	forward_contiguous!();
+}
+
+#[cfg(test)]
+mod tinyvec_tests {
+	#[cfg(feature = "alloc")]
+	use tinyvec::TinyVec;
+	use tinyvec::{ArrayVec, SliceVec};
+
+	use crate::test_util::assert_contiguous;
+
+	#[test]
+	fn contiguous_array_vec() {
+		assert_contiguous(ArrayVec::from([0, 1, 2, 3]));
+	}
+
+	#[test]
+	fn contiguous_slice_vec() {
+		let mut backing = [0, 1, 2, 3];
+		assert_contiguous(SliceVec::from(&mut backing[..]));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn contiguous_tiny_vec() {
+		assert_contiguous(TinyVec::<[i32; 2]>::from(&[0, 1, 2, 3][..]));
+	}
 }